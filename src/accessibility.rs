@@ -0,0 +1,72 @@
+use quicksilver::{
+    input::{ButtonState, Key},
+    lifecycle::Window,
+};
+use text::{FONT_PATH, READABLE_FONT_PATH};
+
+pub const TEXT_SCALES: [f32; 3] = [1., 1.25, 1.5];
+pub const FONT_FAMILIES: [FontFamily; 2] = [FontFamily::Pixel, FontFamily::Readable];
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FontFamily {
+    Pixel,
+    Readable,
+}
+
+impl FontFamily {
+    pub fn path(&self) -> &'static str {
+        match self {
+            FontFamily::Pixel => FONT_PATH,
+            FontFamily::Readable => READABLE_FONT_PATH,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Accessibility {
+    pub font: FontFamily,
+    pub text_scale: f32,
+    pub high_contrast: bool,
+    pub reduced_motion: bool,
+}
+
+impl Accessibility {
+    pub fn new() -> Self {
+        Accessibility {
+            font: FontFamily::Pixel,
+            text_scale: TEXT_SCALES[0],
+            high_contrast: false,
+            reduced_motion: false,
+        }
+    }
+
+    pub fn update(&mut self, window: &Window) -> bool {
+        let previous = *self;
+        let keyboard = window.keyboard();
+        if keyboard[Key::F5] == ButtonState::Pressed {
+            self.font = match self.font {
+                FontFamily::Pixel => FontFamily::Readable,
+                FontFamily::Readable => FontFamily::Pixel,
+            };
+        }
+
+        if keyboard[Key::F6] == ButtonState::Pressed {
+            let next = TEXT_SCALES
+                .iter()
+                .position(|scale| *scale == self.text_scale)
+                .map(|index| (index + 1) % TEXT_SCALES.len())
+                .unwrap_or(0);
+            self.text_scale = TEXT_SCALES[next];
+        }
+
+        if keyboard[Key::F7] == ButtonState::Pressed {
+            self.high_contrast = !self.high_contrast;
+        }
+
+        if keyboard[Key::F8] == ButtonState::Pressed {
+            self.reduced_motion = !self.reduced_motion;
+        }
+
+        previous != *self
+    }
+}
@@ -0,0 +1,177 @@
+use clip::Clip;
+use futures::{future, Future};
+use quicksilver::{
+    graphics::{Font, Image},
+    lifecycle::Asset,
+    load_file, Error, Result,
+};
+use sound_format;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+pub struct Handle<T> {
+    asset: Rc<RefCell<Asset<T>>>,
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Handle {
+            asset: self.asset.clone(),
+        }
+    }
+}
+
+impl<T: 'static> Handle<T> {
+    pub fn load<F>(path: &str, future: F) -> Self
+    where
+        F: Future<Item = T, Error = Error> + 'static,
+    {
+        let context = path.to_string();
+        let future = future.map_err(move |error| {
+            Error::ContextError(format!("Failed to load {}: {}", context, error))
+        });
+
+        Handle {
+            asset: Rc::new(RefCell::new(Asset::new(future))),
+        }
+    }
+
+    pub fn execute(&self, loaded: impl FnOnce(&mut T) -> Result<()>) -> Result<()> {
+        self.asset.borrow_mut().execute(loaded)
+    }
+}
+
+pub trait Pending {
+    fn is_loaded(&self) -> Result<bool>;
+}
+
+impl<T: 'static> Pending for Handle<T> {
+    fn is_loaded(&self) -> Result<bool> {
+        let mut loaded = false;
+        self.execute(|_| {
+            loaded = true;
+            Ok(())
+        })?;
+
+        Ok(loaded)
+    }
+}
+
+pub enum Resource {
+    Image(String),
+    Font(String),
+    Sound(String),
+    Voice(Vec<String>),
+    Track(String),
+}
+
+#[derive(Clone)]
+pub struct Assets(Rc<RefCell<Registry>>);
+
+struct Registry {
+    images: HashMap<String, Handle<Image>>,
+    fonts: HashMap<String, Handle<Font>>,
+    sounds: HashMap<String, Handle<Clip>>,
+    voices: HashMap<String, Handle<Option<Clip>>>,
+    tracks: HashMap<String, Handle<Vec<u8>>>,
+}
+
+impl Assets {
+    pub fn new() -> Self {
+        Assets(Rc::new(RefCell::new(Registry {
+            images: HashMap::new(),
+            fonts: HashMap::new(),
+            sounds: HashMap::new(),
+            voices: HashMap::new(),
+            tracks: HashMap::new(),
+        })))
+    }
+
+    pub fn image(&self, path: &str) -> Handle<Image> {
+        let images = &mut self.0.borrow_mut().images;
+        Self::get_or_load(images, path, || Image::load(path.to_string()))
+    }
+
+    pub fn font(&self, path: &str) -> Handle<Font> {
+        let fonts = &mut self.0.borrow_mut().fonts;
+        Self::get_or_load(fonts, path, || Font::load(path.to_string()))
+    }
+
+    pub fn sound(&self, path: &str) -> Handle<Clip> {
+        let sounds = &mut self.0.borrow_mut().sounds;
+        Self::get_or_load(sounds, path, || Self::load_sound(path))
+    }
+
+    pub fn voice(&self, paths: &[String]) -> Handle<Option<Clip>> {
+        let voices = &mut self.0.borrow_mut().voices;
+        Self::get_or_load(voices, &paths.join(", "), || Self::load_voice(paths))
+    }
+
+    pub fn track(&self, name: &str) -> Handle<Vec<u8>> {
+        let tracks = &mut self.0.borrow_mut().tracks;
+        Self::get_or_load(tracks, name, || Self::load_track(name))
+    }
+
+    pub fn preload(&self, resources: &[Resource]) -> Vec<Box<dyn Pending>> {
+        resources
+            .iter()
+            .map(|resource| -> Box<dyn Pending> {
+                match resource {
+                    Resource::Image(path) => Box::new(self.image(path)),
+                    Resource::Font(path) => Box::new(self.font(path)),
+                    Resource::Sound(path) => Box::new(self.sound(path)),
+                    Resource::Voice(path) => Box::new(self.voice(path)),
+                    Resource::Track(name) => Box::new(self.track(name)),
+                }
+            })
+            .collect()
+    }
+
+    fn load_sound(name: &str) -> Box<dyn Future<Item = Clip, Error = Error>> {
+        Self::resolve(name, Clip::load)
+    }
+
+    fn load_track(name: &str) -> Box<dyn Future<Item = Vec<u8>, Error = Error>> {
+        Self::resolve(name, load_file)
+    }
+
+    fn resolve<T, F>(name: &str, load: fn(String) -> F) -> Box<dyn Future<Item = T, Error = Error>>
+    where
+        T: 'static,
+        F: Future<Item = T, Error = Error> + 'static,
+    {
+        let missing: Box<dyn Future<Item = T, Error = Error>> = Box::new(future::err(
+            Error::ContextError(format!("No supported sound format for {}", name)),
+        ));
+        sound_format::candidates(name)
+            .into_iter()
+            .rev()
+            .fold(missing, |fallback, path| {
+                Box::new(load(path).or_else(move |_| fallback))
+            })
+    }
+
+    fn load_voice(paths: &[String]) -> Box<dyn Future<Item = Option<Clip>, Error = Error>> {
+        let silent: Box<dyn Future<Item = Option<Clip>, Error = Error>> =
+            Box::new(future::ok(None));
+        paths.iter().rev().fold(silent, |fallback, path| {
+            Box::new(Self::load_sound(path).map(Some).or_else(move |_| fallback))
+        })
+    }
+
+    fn get_or_load<T, F>(
+        handles: &mut HashMap<String, Handle<T>>,
+        path: &str,
+        load: impl FnOnce() -> F,
+    ) -> Handle<T>
+    where
+        T: 'static,
+        F: Future<Item = T, Error = Error> + 'static,
+    {
+        handles
+            .entry(path.to_string())
+            .or_insert_with(|| Handle::load(path, load()))
+            .clone()
+    }
+}
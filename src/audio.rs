@@ -0,0 +1,211 @@
+use assets::Handle;
+use captions::Captions;
+use clip::{Clip, Output, Playing};
+use quicksilver::{
+    input::{ButtonState, Key},
+    lifecycle::Window,
+    Result,
+};
+
+const DUCKED_VOLUME: f32 = 0.3;
+const UNVOICED_CAPTION_DURATION: f64 = 2000.;
+const VOLUME_STEP: f32 = 0.1;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Channel {
+    Voice,
+    Music,
+    Sfx,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AudioSettings {
+    pub master: f32,
+    pub voice: f32,
+    pub music: f32,
+    pub sfx: f32,
+    pub muted: bool,
+}
+
+impl AudioSettings {
+    pub fn new() -> Self {
+        AudioSettings {
+            master: 1.,
+            voice: 1.,
+            music: 0.6,
+            sfx: 0.8,
+            muted: false,
+        }
+    }
+}
+
+pub struct Audio {
+    output: Output,
+    settings: AudioSettings,
+    voice_timer: f64,
+    captions: Captions,
+    paused: bool,
+    voices: Vec<Playing>,
+    effects: Vec<Playing>,
+}
+
+impl Audio {
+    pub fn new(settings: AudioSettings) -> Self {
+        Audio {
+            output: Output::open(),
+            settings,
+            voice_timer: 0.,
+            captions: Captions::new(),
+            paused: false,
+            voices: Vec::new(),
+            effects: Vec::new(),
+        }
+    }
+
+    pub fn output(&self) -> &Output {
+        &self.output
+    }
+
+    pub fn settings(&self) -> AudioSettings {
+        self.settings
+    }
+
+    pub fn set_settings(&mut self, settings: AudioSettings) {
+        self.settings = settings;
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.settings.muted
+    }
+
+    pub fn is_ducking(&self) -> bool {
+        !self.paused && self.voice_timer > 0.
+    }
+
+    pub fn toggle_mute(&mut self) {
+        self.settings.muted = !self.settings.muted;
+    }
+
+    pub fn set_volume(&mut self, channel: Option<Channel>, volume: f32) {
+        let volume = volume.clamp(0., 1.);
+        match channel {
+            None => self.settings.master = volume,
+            Some(Channel::Voice) => self.settings.voice = volume,
+            Some(Channel::Music) => self.settings.music = volume,
+            Some(Channel::Sfx) => self.settings.sfx = volume,
+        }
+    }
+
+    pub fn volume(&self, channel: Channel) -> f32 {
+        if self.settings.muted {
+            return 0.;
+        }
+
+        let (volume, ducked) = match channel {
+            Channel::Voice => (self.settings.voice, false),
+            Channel::Music => (self.settings.music, self.is_ducking()),
+            Channel::Sfx => (self.settings.sfx, self.is_ducking()),
+        };
+
+        self.settings.master * volume * if ducked { DUCKED_VOLUME } else { 1. }
+    }
+
+    pub fn captions(&mut self) -> &mut Captions {
+        &mut self.captions
+    }
+
+    pub fn caption(&mut self, caption: &str, duration: f64) {
+        self.captions.show(caption, duration);
+    }
+
+    pub fn stop_voice(&mut self) {
+        self.voices.clear();
+        self.voice_timer = 0.;
+    }
+
+    pub fn stop(&mut self) {
+        self.stop_voice();
+        self.effects.clear();
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+        for playing in self.voices.iter().chain(self.effects.iter()) {
+            if paused {
+                playing.pause();
+            } else {
+                playing.resume();
+            }
+        }
+    }
+
+    pub fn update(&mut self, window: &Window) {
+        self.voices.retain(|voice| !voice.is_done());
+        self.effects.retain(|effect| !effect.is_done());
+        self.captions.handle_keys(window);
+        if !self.paused {
+            self.captions.update(window);
+            self.voice_timer = (self.voice_timer - window.update_rate()).max(0.);
+        }
+
+        let keyboard = window.keyboard();
+        let master = self.settings.master;
+        if keyboard[Key::Minus] == ButtonState::Pressed {
+            self.set_volume(None, master - VOLUME_STEP);
+        } else if keyboard[Key::Equals] == ButtonState::Pressed {
+            self.set_volume(None, master + VOLUME_STEP);
+        }
+    }
+
+    pub fn play(&mut self, clip: &Handle<Clip>, channel: Channel) -> Result<()> {
+        let volume = self.volume(channel);
+        if volume <= 0. {
+            return Ok(());
+        }
+
+        let mut playing = None;
+        let output = &self.output;
+        clip.execute(|clip| {
+            playing = Some(clip.play(output, volume)?);
+            Ok(())
+        })?;
+
+        match channel {
+            Channel::Voice => self.voices.extend(playing),
+            _ => self.effects.extend(playing),
+        }
+
+        Ok(())
+    }
+
+    pub fn play_voice(&mut self, clip: &Handle<Option<Clip>>, caption: &str) -> Result<bool> {
+        if self.is_ducking() {
+            return Ok(false);
+        }
+
+        let mut played = None;
+        let mut playing = None;
+        let volume = self.volume(Channel::Voice);
+        let output = &self.output;
+        clip.execute(|clip| {
+            played = Some(match clip {
+                Some(clip) => {
+                    if volume > 0. {
+                        playing = Some(clip.play(output, volume)?);
+                    }
+                    clip.duration()
+                }
+                None => UNVOICED_CAPTION_DURATION,
+            });
+            Ok(())
+        })?;
+
+        self.voices.extend(playing);
+        if let Some(duration) = played {
+            self.voice_timer = duration;
+            self.captions.show(caption, duration);
+        }
+
+        Ok(played.is_some())
+    }
+}
@@ -0,0 +1,47 @@
+use constant::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use quicksilver::geom::Vector;
+
+const SMOOTHING: f32 = 0.1;
+const SMOOTHING_RATE: f64 = 1000. / 60.;
+
+pub struct Camera {
+    position: Vector,
+    following: bool,
+}
+
+impl Camera {
+    pub fn new() -> Self {
+        Camera {
+            position: Vector::ZERO,
+            following: false,
+        }
+    }
+
+    pub fn offset(&self) -> Vector {
+        self.position
+    }
+
+    pub fn follow(&mut self, target: Vector, bounds: Vector, elapsed: f64) {
+        let destination = Self::clamp(target - Self::size() / 2, bounds);
+        if !self.following {
+            self.position = destination;
+            self.following = true;
+            return;
+        }
+
+        let weight = 1. - (1. - SMOOTHING).powf((elapsed / SMOOTHING_RATE) as f32);
+        self.position = Self::clamp(
+            self.position + (destination - self.position) * weight,
+            bounds,
+        );
+    }
+
+    fn clamp(position: Vector, bounds: Vector) -> Vector {
+        let max = (bounds - Self::size()).max(Vector::ZERO);
+        position.clamp(Vector::ZERO, max)
+    }
+
+    fn size() -> Vector {
+        Vector::new(WINDOW_WIDTH, WINDOW_HEIGHT)
+    }
+}
@@ -0,0 +1,88 @@
+use constant::{CAPTION_Z, WINDOW_WIDTH};
+use quicksilver::{
+    geom::{Rectangle, Shape, Transform, Vector},
+    graphics::{Background::Col, Background::Img, Color},
+    input::{ButtonState, Key},
+    lifecycle::Window,
+    Result,
+};
+use text::{Text, TextStyle};
+
+const TOP: f32 = 72.;
+const PADDING: f32 = 8.;
+
+lazy_static! {
+    static ref CAPTION: TextStyle = TextStyle::new(24.0, Color::WHITE);
+    static ref BACKGROUND: Color = Color::BLACK.with_alpha(0.75);
+}
+
+pub struct Captions {
+    enabled: bool,
+    caption: Option<String>,
+    timer: f64,
+}
+
+impl Captions {
+    pub fn new() -> Self {
+        Captions {
+            enabled: false,
+            caption: None,
+            timer: 0.,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn show(&mut self, caption: &str, duration: f64) {
+        self.caption = Some(caption.to_string());
+        self.timer = duration;
+    }
+
+    pub fn handle_keys(&mut self, window: &Window) {
+        if window.keyboard()[Key::F9] == ButtonState::Pressed {
+            self.enabled = !self.enabled;
+        }
+    }
+
+    pub fn update(&mut self, window: &Window) {
+        self.timer -= window.update_rate();
+        if self.timer <= 0. {
+            self.caption = None;
+        }
+    }
+
+    pub fn draw(&mut self, window: &mut Window, text: &mut Text) -> Result<()> {
+        let caption = match (self.enabled, &self.caption) {
+            (true, Some(caption)) => caption,
+            _ => return Ok(()),
+        };
+
+        if let Some(caption_text) = text.render(caption, &CAPTION)? {
+            let area = caption_text.area().with_center((
+                WINDOW_WIDTH as f32 / 2.,
+                TOP + caption_text.area().height() / 2.,
+            ));
+            let padding = Vector::new(PADDING, PADDING);
+            window.draw_ex(
+                &Rectangle::new(area.pos - padding, area.size + padding * 2),
+                Col(*BACKGROUND),
+                Transform::IDENTITY,
+                CAPTION_Z,
+            );
+            window.draw_ex(
+                &area,
+                Img(&caption_text),
+                Transform::IDENTITY,
+                CAPTION_Z + 1,
+            );
+        }
+
+        Ok(())
+    }
+}
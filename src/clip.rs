@@ -0,0 +1,186 @@
+use futures::Future;
+#[cfg(target_arch = "wasm32")]
+use futures::{future, Async};
+#[cfg(not(target_arch = "wasm32"))]
+use quicksilver::load_file;
+use quicksilver::{Error, Result};
+#[cfg(not(target_arch = "wasm32"))]
+use rodio;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+#[cfg(target_arch = "wasm32")]
+use stdweb::{self, unstable::TryInto};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct Output {
+    device: Option<rodio::Device>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Output {
+    pub fn open() -> Self {
+        Output {
+            device: rodio::default_output_device(),
+        }
+    }
+
+    pub fn sink(&self) -> Result<rodio::Sink> {
+        self.device
+            .as_ref()
+            .map(rodio::Sink::new)
+            .ok_or_else(|| Error::ContextError("No audio output device".to_string()))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct Clip {
+    data: Arc<[u8]>,
+    duration: f64,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Clip {
+    pub fn load(path: String) -> impl Future<Item = Clip, Error = Error> {
+        load_file(path).and_then(Clip::decode)
+    }
+
+    fn decode(data: Vec<u8>) -> Result<Clip> {
+        use rodio::{Decoder, Source};
+        use std::io::Cursor;
+
+        let data: Arc<[u8]> = Arc::from(data);
+        let decoder = Decoder::new(Cursor::new(data.clone()))?;
+        let duration = match decoder.total_duration() {
+            Some(duration) => {
+                duration.as_secs() as f64 * 1000. + f64::from(duration.subsec_nanos()) / 1e6
+            }
+            None => {
+                let rate = f64::from(decoder.sample_rate()) * f64::from(decoder.channels());
+                decoder.count() as f64 * 1000. / rate
+            }
+        };
+        Ok(Clip { data, duration })
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    pub fn play(&self, output: &Output, volume: f32) -> Result<Playing> {
+        use rodio::Decoder;
+        use std::io::Cursor;
+
+        let mut sink = output.sink()?;
+        sink.set_volume(volume);
+        sink.append(Decoder::new(Cursor::new(self.data.clone()))?);
+        Ok(Playing { sink })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct Playing {
+    sink: rodio::Sink,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Playing {
+    pub fn is_done(&self) -> bool {
+        self.sink.empty()
+    }
+
+    pub fn pause(&self) {
+        self.sink.pause();
+    }
+
+    pub fn resume(&self) {
+        self.sink.play();
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub struct Output;
+
+#[cfg(target_arch = "wasm32")]
+impl Output {
+    pub fn open() -> Self {
+        Output
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub struct Clip {
+    element: stdweb::Value,
+    duration: f64,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Clip {
+    pub fn load(path: String) -> impl Future<Item = Clip, Error = Error> {
+        let element = js! {
+            var audio = new Audio(@{&path});
+            audio.failed = false;
+            audio.onerror = function() { audio.failed = true; };
+            return audio;
+        };
+        future::poll_fn(move || {
+            let failed: bool = js!(return @{&element}.failed;).try_into().unwrap_or(true);
+            let ready: i32 = js!(return @{&element}.readyState;).try_into().unwrap_or(0);
+            if failed {
+                Err(Error::ContextError(format!("Failed to load {}", path)))
+            } else if ready == 4 {
+                let duration: f64 = js!(return @{&element}.duration * 1000;)
+                    .try_into()
+                    .unwrap_or(0.);
+                Ok(Async::Ready(Clip {
+                    element: element.clone(),
+                    duration,
+                }))
+            } else {
+                Ok(Async::NotReady)
+            }
+        })
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    pub fn play(&self, _output: &Output, volume: f32) -> Result<Playing> {
+        let element = js! {
+            var audio = @{&self.element}.cloneNode();
+            audio.volume = @{volume};
+            audio.play();
+            return audio;
+        };
+        Ok(Playing { element })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub struct Playing {
+    element: stdweb::Value,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Playing {
+    pub fn is_done(&self) -> bool {
+        js!(return @{&self.element}.ended;)
+            .try_into()
+            .unwrap_or(true)
+    }
+
+    pub fn pause(&self) {
+        js! { @(no_return) @{&self.element}.pause(); }
+    }
+
+    pub fn resume(&self) {
+        js! { @(no_return) @{&self.element}.play(); }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for Playing {
+    fn drop(&mut self) {
+        js! { @(no_return) @{&self.element}.pause(); }
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(target_arch = "wasm32")]
+use stdweb::web::Date;
+
+pub const STEP: f64 = 1000. / 64.;
+const MAX_STEPS: u32 = 8;
+
+pub struct Clock {
+    accumulator: f64,
+    advanced_at: Option<f64>,
+}
+
+impl Clock {
+    pub fn new() -> Self {
+        Clock {
+            accumulator: 0.,
+            advanced_at: None,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.accumulator = 0.;
+        self.advanced_at = None;
+    }
+
+    pub fn advance(&mut self, now: f64) -> u32 {
+        let elapsed = self.advanced_at.map(|then| now - then).unwrap_or(0.);
+        self.accumulator += elapsed.max(0.);
+        self.advanced_at = Some(now);
+        let mut steps = 0;
+        while self.accumulator >= STEP && steps < MAX_STEPS {
+            self.accumulator -= STEP;
+            steps += 1;
+        }
+
+        if steps == MAX_STEPS {
+            self.accumulator = 0.;
+        }
+
+        steps
+    }
+
+    pub fn alpha(&self, now: f64) -> f64 {
+        let elapsed = self.advanced_at.map(|then| now - then).unwrap_or(0.);
+        ((self.accumulator + elapsed) / STEP).clamp(0., 1.)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn now() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as f64 * 1000. + duration.subsec_millis() as f64)
+        .unwrap_or(0.)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn now() -> f64 {
+    Date::now()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps(slice: f64, duration: f64) -> u32 {
+        let mut clock = Clock::new();
+        let mut steps = clock.advance(0.);
+        let mut now = 0.;
+        while now < duration {
+            now += slice;
+            steps += clock.advance(now);
+        }
+        steps
+    }
+
+    #[test]
+    fn step_count_does_not_depend_on_the_update_rate() {
+        assert_eq!(steps(STEP, 1000.), 64);
+        assert_eq!(steps(10., 1000.), 64);
+        assert_eq!(steps(20., 1000.), 64);
+        assert_eq!(steps(25., 1000.), 64);
+    }
+
+    #[test]
+    fn the_first_update_only_starts_the_clock() {
+        let mut clock = Clock::new();
+        assert_eq!(clock.advance(5000.), 0);
+        assert_eq!(clock.advance(5000. + STEP), 1);
+    }
+
+    #[test]
+    fn leftover_time_carries_into_the_next_update() {
+        let mut clock = Clock::new();
+        clock.advance(0.);
+        assert_eq!(clock.advance(STEP / 2.), 0);
+        assert_eq!(clock.alpha(STEP / 2.), 0.5);
+        assert_eq!(clock.advance(STEP), 1);
+        assert_eq!(clock.advance(STEP * 3.5), 2);
+        assert_eq!(clock.alpha(STEP * 3.5), 0.5);
+        assert_eq!(clock.advance(STEP * 4.), 1);
+    }
+
+    #[test]
+    fn long_stalls_are_dropped_instead_of_replayed() {
+        let mut clock = Clock::new();
+        clock.advance(0.);
+        assert_eq!(clock.advance(STEP * 100.), MAX_STEPS);
+        assert_eq!(clock.advance(STEP * 100.), 0);
+        assert_eq!(clock.advance(STEP * 101.), 1);
+    }
+
+    #[test]
+    fn resetting_skips_the_time_spent_away() {
+        let mut clock = Clock::new();
+        clock.advance(0.);
+        clock.advance(STEP / 2.);
+        clock.reset();
+        assert_eq!(clock.advance(60000.), 0);
+        assert_eq!(clock.alpha(60000.), 0.);
+        assert_eq!(clock.advance(60000. + STEP), 1);
+    }
+}
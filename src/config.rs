@@ -0,0 +1,56 @@
+use accessibility::Accessibility;
+use audio::AudioSettings;
+use locale::Language;
+use quicksilver::saving::{load, save};
+
+const APP_NAME: &str = "roborex";
+const CONFIG_PROFILE: &str = "config";
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ControlScheme {
+    Both,
+    Keyboard,
+    Mouse,
+}
+
+impl ControlScheme {
+    pub fn allows_keyboard(&self) -> bool {
+        *self != ControlScheme::Mouse
+    }
+
+    pub fn allows_mouse(&self) -> bool {
+        *self != ControlScheme::Keyboard
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Config {
+    pub walking_speed: f32,
+    pub audio: AudioSettings,
+    pub captions: bool,
+    pub accessibility: Accessibility,
+    pub controls: ControlScheme,
+    #[serde(default)]
+    pub language: Language,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Config {
+            walking_speed: 1.,
+            audio: AudioSettings::new(),
+            captions: false,
+            accessibility: Accessibility::new(),
+            controls: ControlScheme::Both,
+            language: Language::English,
+        }
+    }
+
+    pub fn load() -> Self {
+        load(APP_NAME, CONFIG_PROFILE).unwrap_or_else(|_| Config::new())
+    }
+
+    pub fn save(&self) {
+        let _ = save(APP_NAME, CONFIG_PROFILE, self);
+    }
+}
@@ -10,3 +10,8 @@ pub const WINDOW_HEIGHT: u32 = 600;
 pub const COLLECTIBLE_Z: u32 = 10;
 pub const GATE_Z: u32 = 11;
 pub const PLAYER_Z: u32 = 12;
+pub const DEBUG_Z: u32 = 15;
+pub const HUD_Z: u32 = 20;
+pub const CAPTION_Z: u32 = 25;
+pub const NOTICE_Z: u32 = 30;
+pub const TRANSITION_Z: u32 = 100;
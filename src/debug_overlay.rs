@@ -0,0 +1,101 @@
+use constant::{DEBUG_Z, WINDOW_HEIGHT};
+use primitive::Position;
+use quicksilver::{
+    geom::{Rectangle, Shape, Transform, Vector},
+    graphics::{Background::Col, Background::Img, Color},
+    input::{ButtonState, Key},
+    lifecycle::Window,
+    Result,
+};
+use text::{Text, TextStyle};
+
+const LINE_WIDTH: f32 = 2.;
+const MARGIN: f32 = 8.;
+
+lazy_static! {
+    pub static ref PATH: Color = Color::GREEN.with_alpha(0.25);
+    pub static ref NON_PATH: Color = Color::RED.with_alpha(0.25);
+    pub static ref EMPTY: Color = Color::BLUE.with_alpha(0.25);
+    pub static ref GATE: Color = Color::YELLOW;
+    pub static ref COLLECTIBLE: Color = Color::MAGENTA;
+    static ref INFO: TextStyle = TextStyle::new(16.0, Color::WHITE);
+    static ref BACKGROUND: Color = Color::BLACK.with_alpha(0.6);
+}
+
+pub struct DebugOverlay {
+    enabled: bool,
+}
+
+impl DebugOverlay {
+    pub fn new() -> Self {
+        DebugOverlay { enabled: false }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn update(&mut self, window: &Window) {
+        if window.keyboard()[Key::F3] == ButtonState::Pressed {
+            self.enabled = !self.enabled;
+        }
+    }
+
+    pub fn tint(window: &mut Window, cell: Rectangle, color: Color) {
+        window.draw_ex(&cell, Col(color), Transform::IDENTITY, DEBUG_Z);
+    }
+
+    pub fn outline(window: &mut Window, cell: Rectangle, color: Color) {
+        let edges = [
+            Rectangle::new(cell.pos, (cell.size.x, LINE_WIDTH)),
+            Rectangle::new(
+                cell.pos + Vector::new(0., cell.size.y - LINE_WIDTH),
+                (cell.size.x, LINE_WIDTH),
+            ),
+            Rectangle::new(cell.pos, (LINE_WIDTH, cell.size.y)),
+            Rectangle::new(
+                cell.pos + Vector::new(cell.size.x - LINE_WIDTH, 0.),
+                (LINE_WIDTH, cell.size.y),
+            ),
+        ];
+        for edge in edges.iter() {
+            window.draw_ex(edge, Col(color), Transform::IDENTITY, DEBUG_Z + 1);
+        }
+    }
+
+    pub fn draw(&mut self, window: &mut Window, text: &Text, tile: Option<Position>) -> Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let fps = window.current_fps();
+        let frame_time = if fps > 0. { 1000. / fps } else { 0. };
+        let tile = match tile {
+            Some(tile) => format!("Tile ({}, {})", tile.x, tile.y),
+            None => "Tile -".to_string(),
+        };
+        let lines = [tile, format!("{:.1} ms   {:.0} FPS", frame_time, fps)];
+        let mut y = WINDOW_HEIGHT as f32 - MARGIN;
+        for line in lines.iter().rev() {
+            if let Some(line_text) = text.render_uncached(line, &INFO)? {
+                let area = line_text.area();
+                y -= area.size.y + MARGIN;
+                let position = Vector::new(MARGIN, y);
+                window.draw_ex(
+                    &Rectangle::new(position, area.size() + Vector::new(MARGIN, MARGIN)),
+                    Col(*BACKGROUND),
+                    Transform::IDENTITY,
+                    DEBUG_Z + 2,
+                );
+                window.draw_ex(
+                    &area.translate(position + Vector::new(MARGIN, MARGIN) / 2),
+                    Img(&line_text),
+                    Transform::IDENTITY,
+                    DEBUG_Z + 3,
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
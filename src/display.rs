@@ -0,0 +1,122 @@
+use constant::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use quicksilver::{
+    geom::{Rectangle, Transform, Vector},
+    graphics::{Background::Col, Color, View},
+    input::{ButtonState, Key},
+    lifecycle::Window,
+    Result,
+};
+
+const LETTERBOX_Z: u32 = 1000;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Scaling {
+    Letterbox,
+    Integer,
+}
+
+pub struct Display {
+    scaling: Scaling,
+    fullscreen: bool,
+    screen: Vector,
+}
+
+impl Display {
+    pub fn new() -> Self {
+        Display {
+            scaling: Scaling::Letterbox,
+            fullscreen: false,
+            screen: Self::size(),
+        }
+    }
+
+    pub fn update(&mut self, window: &mut Window) -> Result<()> {
+        if window.keyboard()[Key::F11] == ButtonState::Pressed {
+            self.fullscreen = !self.fullscreen;
+            window.set_fullscreen(self.fullscreen);
+        }
+
+        if window.keyboard()[Key::F10] == ButtonState::Pressed {
+            self.scaling = match self.scaling {
+                Scaling::Letterbox => Scaling::Integer,
+                Scaling::Integer => Scaling::Letterbox,
+            };
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let available = Self::available_size();
+            if available != window.screen_size() {
+                window.set_size(available);
+            }
+        }
+
+        self.screen = window.screen_size();
+        window.set_view(self.view(Vector::ZERO));
+
+        Ok(())
+    }
+
+    pub fn view(&self, offset: Vector) -> View {
+        let size = self.screen / self.scale();
+        let margin = (size - Self::size()) / 2;
+        View::new(Rectangle::new(offset - margin, size))
+    }
+
+    pub fn pointer(window: &Window) -> Vector {
+        window.mouse().pos()
+    }
+
+    pub fn viewport() -> Rectangle {
+        Rectangle::new_sized(Self::size())
+    }
+
+    pub fn draw_letterbox(&self, window: &mut Window) -> Result<()> {
+        let size = Self::size();
+        let margin = (self.screen / self.scale() - size) / 2;
+        if margin.x > 0. {
+            let bar = Vector::new(margin.x, size.y + margin.y * 2.);
+            Self::draw_bar(window, Rectangle::new(-margin, bar));
+            Self::draw_bar(window, Rectangle::new((size.x, -margin.y), bar));
+        }
+
+        if margin.y > 0. {
+            let bar = Vector::new(size.x + margin.x * 2., margin.y);
+            Self::draw_bar(window, Rectangle::new(-margin, bar));
+            Self::draw_bar(window, Rectangle::new((-margin.x, size.y), bar));
+        }
+
+        Ok(())
+    }
+
+    fn draw_bar(window: &mut Window, bar: Rectangle) {
+        window.draw_ex(&bar, Col(Color::BLACK), Transform::IDENTITY, LETTERBOX_Z);
+    }
+
+    fn scale(&self) -> f32 {
+        let size = Self::size();
+        let scale = (self.screen.x / size.x).min(self.screen.y / size.y);
+        match self.scaling {
+            Scaling::Letterbox => scale,
+            Scaling::Integer if scale < 1. => scale,
+            Scaling::Integer => scale.floor(),
+        }
+    }
+
+    fn size() -> Vector {
+        Vector::new(WINDOW_WIDTH, WINDOW_HEIGHT)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn available_size() -> Vector {
+        use stdweb::unstable::TryInto;
+
+        let width: f64 = js!(return window.innerWidth;)
+            .try_into()
+            .unwrap_or(WINDOW_WIDTH as f64);
+        let height: f64 = js!(return window.innerHeight;)
+            .try_into()
+            .unwrap_or(WINDOW_HEIGHT as f64);
+        Vector::new(width as f32, height as f32)
+    }
+}
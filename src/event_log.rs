@@ -0,0 +1,237 @@
+use clock;
+use export;
+use serde::Serializer;
+use serde_json;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+impl Format {
+    fn extension(&self) -> &'static str {
+        match *self {
+            Format::Csv => "csv",
+            Format::Json => "json",
+        }
+    }
+
+    fn mime_type(&self) -> &'static str {
+        match *self {
+            Format::Csv => "text/csv",
+            Format::Json => "application/json",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Activity {
+    LevelStarted,
+    LetterCollected { letter: char, slot: usize },
+    WrongLetter { letter: char, slot: usize },
+    ReplayPressed,
+    GateOpened,
+    LevelCompleted,
+}
+
+impl Activity {
+    fn name(&self) -> &'static str {
+        match *self {
+            Activity::LevelStarted => "level_started",
+            Activity::LetterCollected { .. } => "letter_collected",
+            Activity::WrongLetter { .. } => "wrong_letter",
+            Activity::ReplayPressed => "replay_pressed",
+            Activity::GateOpened => "gate_opened",
+            Activity::LevelCompleted => "level_completed",
+        }
+    }
+
+    fn letter(&self) -> Option<(char, usize)> {
+        match *self {
+            Activity::LetterCollected { letter, slot } | Activity::WrongLetter { letter, slot } => {
+                Some((letter, slot))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub profile: String,
+    #[serde(serialize_with = "serialize_level")]
+    pub level: u32,
+    pub word: String,
+    #[serde(flatten)]
+    pub activity: Activity,
+}
+
+#[derive(Serialize)]
+pub struct EventLog {
+    #[serde(skip)]
+    profile: String,
+    entries: Vec<Entry>,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        EventLog {
+            profile: String::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn set_profile(&mut self, profile: &str) {
+        self.profile = profile.to_string();
+    }
+
+    pub fn since(&self, index: usize) -> &[Entry] {
+        &self.entries[index.min(self.entries.len())..]
+    }
+
+    pub fn log(&mut self, level: u32, word: &str, activity: Activity) {
+        self.push(clock::now() as u64, level, word, activity);
+    }
+
+    fn push(&mut self, timestamp: u64, level: u32, word: &str, activity: Activity) {
+        self.entries.push(Entry {
+            timestamp,
+            profile: self.profile.clone(),
+            level,
+            word: word.to_string(),
+            activity,
+        });
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("timestamp,profile,level,word,event,letter,slot\n");
+        for entry in self.entries.iter() {
+            let (letter, slot) = match entry.activity.letter() {
+                Some((letter, slot)) => (letter.to_string(), slot.to_string()),
+                None => (String::new(), String::new()),
+            };
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                entry.timestamp,
+                quote(&entry.profile),
+                entry.level + 1,
+                quote(&entry.word),
+                entry.activity.name(),
+                quote(&letter),
+                slot
+            ));
+        }
+
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    pub fn export(&self, format: Format) -> Option<String> {
+        let contents = match format {
+            Format::Csv => self.to_csv(),
+            Format::Json => self.to_json(),
+        };
+        let file_name = format!(
+            "roborex-events-{}.{}",
+            clock::now() as u64,
+            format.extension()
+        );
+        export::write(&file_name, &contents, format.mime_type())
+    }
+}
+
+fn serialize_level<S: Serializer>(level: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u32(level + 1)
+}
+
+fn quote(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log() -> EventLog {
+        let mut log = EventLog::new();
+        log.set_profile("Ada");
+        log.push(1000, 0, "AB", Activity::LevelStarted);
+        log.push(
+            1500,
+            0,
+            "AB",
+            Activity::WrongLetter {
+                letter: 'B',
+                slot: 0,
+            },
+        );
+        log.push(
+            2000,
+            0,
+            "AB",
+            Activity::LetterCollected {
+                letter: 'A',
+                slot: 0,
+            },
+        );
+        log.push(2500, 0, "AB", Activity::ReplayPressed);
+        log
+    }
+
+    #[test]
+    fn csv_has_one_row_per_entry() {
+        assert_eq!(
+            log().to_csv(),
+            "timestamp,profile,level,word,event,letter,slot\n\
+             1000,Ada,1,AB,level_started,,\n\
+             1500,Ada,1,AB,wrong_letter,B,0\n\
+             2000,Ada,1,AB,letter_collected,A,0\n\
+             2500,Ada,1,AB,replay_pressed,,\n"
+        );
+    }
+
+    #[test]
+    fn csv_quotes_fields_that_need_it() {
+        let mut log = EventLog::new();
+        log.set_profile("Ada, \"Jr\"");
+        log.push(1000, 0, "AB", Activity::LevelStarted);
+        assert_eq!(
+            log.to_csv(),
+            "timestamp,profile,level,word,event,letter,slot\n\
+             1000,\"Ada, \"\"Jr\"\"\",1,AB,level_started,,\n"
+        );
+    }
+
+    #[test]
+    fn entries_since_an_index_are_the_newer_ones() {
+        let log = log();
+        assert_eq!(log.since(0).len(), 4);
+        assert_eq!(log.since(3)[0].activity, Activity::ReplayPressed);
+        assert!(log.since(4).is_empty());
+        assert!(log.since(9).is_empty());
+    }
+
+    #[test]
+    fn json_flattens_the_activity_into_each_entry() {
+        let json: serde_json::Value = serde_json::from_str(&log().to_json()).unwrap();
+        let entries = json["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries[1]["event"], "wrong_letter");
+        assert_eq!(entries[1]["letter"], "B");
+        assert_eq!(entries[1]["slot"], 0);
+        assert_eq!(entries[1]["level"], 1);
+        assert_eq!(entries[1]["profile"], "Ada");
+        assert_eq!(entries[3]["event"], "replay_pressed");
+        assert!(entries[3].get("letter").is_none());
+    }
+}
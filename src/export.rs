@@ -0,0 +1,30 @@
+#[cfg(not(target_arch = "wasm32"))]
+const APP_NAME: &str = "roborex";
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn write(file_name: &str, contents: &str, _mime_type: &str) -> Option<String> {
+    use dirs;
+    use std::fs;
+
+    let directory = dirs::data_dir()?.join(APP_NAME);
+    fs::create_dir_all(&directory).ok()?;
+    let path = directory.join(file_name);
+    fs::write(&path, contents).ok()?;
+    Some(path.display().to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn write(file_name: &str, contents: &str, mime_type: &str) -> Option<String> {
+    js! { @(no_return)
+        var blob = new Blob([@{contents}], { type: @{mime_type} });
+        var url = URL.createObjectURL(blob);
+        var link = document.createElement("a");
+        link.href = url;
+        link.download = @{file_name};
+        document.body.appendChild(link);
+        link.click();
+        document.body.removeChild(link);
+        setTimeout(function() { URL.revokeObjectURL(url); }, 1000);
+    }
+    Some(file_name.to_string())
+}
@@ -0,0 +1,44 @@
+use constant::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use locale::Message;
+use quicksilver::{
+    geom::{Shape, Transform},
+    graphics::{Background::Img, Color},
+    lifecycle::Window,
+    Result,
+};
+use scene::{Context, Scene, SceneChange};
+use text::TextStyle;
+
+lazy_static! {
+    static ref TITLE: TextStyle = TextStyle::new(42.0, Color::WHITE);
+}
+
+pub struct Finished;
+
+impl Finished {
+    pub fn new() -> Self {
+        Finished
+    }
+}
+
+impl Scene for Finished {
+    fn update(&mut self, _window: &mut Window, _context: &mut Context) -> Result<SceneChange> {
+        Ok(SceneChange::Stay)
+    }
+
+    fn draw(&mut self, window: &mut Window, context: &mut Context) -> Result<()> {
+        let title = context.config.language.text(Message::WellDone);
+        if let Some(finished_text) = context.text.render(title, &TITLE)? {
+            window.draw_ex(
+                &finished_text
+                    .area()
+                    .with_center((WINDOW_WIDTH / 2, WINDOW_HEIGHT / 2)),
+                Img(&finished_text),
+                Transform::IDENTITY,
+                1,
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -1,88 +1,77 @@
+use assets::{Assets, Handle};
+use constant::GATE_Z;
+use debug_overlay::{self, DebugOverlay};
 use futures::{future, Future};
 use game_layer::GameLayer;
-use gate::Gate;
 use grid::Grid;
 use primitive::{Dimension, Position};
 use quicksilver::{
-    geom::Rectangle,
-    graphics::Image,
-    lifecycle::{Asset, Window},
+    geom::{Rectangle, Transform, Vector},
+    graphics::{Background::Img, Color, Image, Surface},
+    lifecycle::Window,
     load_file, Error, Result,
 };
-use std::cmp::max;
-use std::collections::HashSet;
 use std::fmt;
 use std::path::{Path, PathBuf};
+use terrain::{Cell, Terrain};
 use tiled;
 
-lazy_static! {
-    static ref PATH_SET: HashSet<u32> = {
-        let mut m = HashSet::new();
-        m.insert(197);
-        m.insert(196);
-        m.insert(221);
-        m.insert(271);
-        m.insert(246);
-        m.insert(248);
-        m.insert(269);
-        m.insert(268);
-        m.insert(270);
-        m.insert(277);
-        m.insert(278);
-        m.insert(279);
-        m.insert(280);
-        m.insert(256);
-        m.insert(228);
-        m.insert(229);
-        m.insert(230);
-        m.insert(231);
-        m.insert(232);
-        m.insert(233);
-        m.insert(234);
-        m.insert(154);
-        m.insert(155);
-        m.insert(160);
-        m.insert(161);
-        m
-    };
-}
+const GATE_INDEX: u32 = 214;
 
 pub struct GameMap {
     layers: Vec<GameLayer>,
-    grid: GridMap,
-    gate: Gate,
+    terrain: Terrain,
     tileset: tiled::Tileset,
-    tileset_image: Asset<Image>,
+    tileset_image: Handle<Image>,
+    gate_rec: Option<Rectangle>,
+    cache: Option<Surface>,
+    cached_gate: Option<bool>,
 }
 
-type GridMap = Vec<Vec<Grid>>;
-
 impl GameMap {
     pub fn load<'a, P: 'static + AsRef<Path>>(
         path: P,
         gate_position: Position,
+        assets: &Assets,
     ) -> impl Future<Item = GameMap, Error = Error> {
+        let assets = assets.clone();
+        let directory = path
+            .as_ref()
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
         load_file(PathBuf::from(path.as_ref()))
-            .map(move |data| Self::from_bytes(data.as_slice(), gate_position))
+            .map(move |data| Self::from_bytes(data.as_slice(), &directory, gate_position, &assets))
             .and_then(future::result)
     }
 
-    pub fn gate_position(&self) -> &Position {
-        &self.gate.position
+    pub fn terrain(&self) -> &Terrain {
+        &self.terrain
+    }
+
+    pub fn terrain_mut(&mut self) -> &mut Terrain {
+        &mut self.terrain
+    }
+
+    pub fn tileset_image(&self) -> &Handle<Image> {
+        &self.tileset_image
     }
 
-    pub fn open_gate(&mut self) {
-        self.gate.open();
+    pub fn size(&self) -> Vector {
+        Grid::to_map_size(self.terrain.columns(), self.terrain.rows())
     }
 
-    pub fn from_bytes(raw: &[u8], gate_position: Position) -> Result<GameMap> {
+    pub fn from_bytes(
+        raw: &[u8],
+        directory: &Path,
+        gate_position: Position,
+        assets: &Assets,
+    ) -> Result<GameMap> {
         let map = tiled::parse(raw)
             .map_err(|_| Error::ContextError("Error loading level".to_string()))?;
         let tileset = &map.tilesets[0];
-        let tileset_image = Asset::new(Image::load(format!(
-            "resources/tiled/{}",
-            tileset.images[0].source
-        )));
+        let tileset_path = directory.join(&tileset.images[0].source);
+        let tileset_image = assets.image(&tileset_path.to_string_lossy());
         let tile_dimension = Dimension::new(map.tile_width, map.tile_height);
         let image_dimension = Dimension::new(
             tileset.images[0].width as u32,
@@ -95,105 +84,92 @@ impl GameMap {
             .map(|layer| Self::to_game_layer(&layer, &tile_dimension, &image_dimension))
             .collect();
 
-        let grid: GridMap = Self::to_grid(map.layers);
-        let gate: Gate = Gate::new(gate_position, tile_dimension, image_dimension);
+        let terrain = Terrain::from_map(&map, gate_position);
+        let gate_rec = Self::to_rectangle(GATE_INDEX, &tile_dimension, &image_dimension);
         let game_map = GameMap {
             layers,
-            grid,
-            gate,
+            terrain,
             tileset: tileset.clone(),
             tileset_image,
+            gate_rec,
+            cache: None,
+            cached_gate: None,
         };
         Ok(game_map)
     }
 
     pub fn draw(&mut self, window: &mut Window) -> Result<()> {
+        let size = self.size();
         let layers = &mut self.layers;
         let len = layers.len();
-        let gate = &mut self.gate;
+        let gate = self.terrain.gate();
+        let gate_closed = gate.is_closed();
+        let gate_rec = self.gate_rec;
+        let cache = &mut self.cache;
+        let cached_gate = &mut self.cached_gate;
         self.tileset_image.execute(move |tileset| {
-            for i in 0..len {
-                layers[i].draw(window, tileset)?;
+            if *cached_gate != Some(gate_closed) || cache.is_none() {
+                let surface = match cache.take() {
+                    Some(surface) => surface,
+                    None => Surface::new(size.x as u32, size.y as u32)?,
+                };
+                surface.render_to(window, |window| {
+                    window.clear(Color::BLACK)?;
+                    for i in 0..len {
+                        layers[i].draw(window, tileset)?;
+                    }
+
+                    if let (true, Some(gate_rec)) = (gate_closed, gate_rec) {
+                        Self::draw_gate(window, tileset, gate_rec, &gate.position);
+                    }
+                    Ok(())
+                })?;
+                *cache = Some(surface);
+                *cached_gate = Some(gate_closed);
             }
 
-            gate.draw(window, tileset)?;
+            if let Some(surface) = cache {
+                window.draw_ex(
+                    &Rectangle::new_sized(size),
+                    Img(surface.image()),
+                    Transform::IDENTITY,
+                    1,
+                );
+            }
             Ok(())
         })?;
 
         Ok(())
     }
 
-    pub fn can_walk_to(&self, position: &Position) -> bool {
-        let x = position.x as usize;
-        let y = position.y as usize;
-        if self.grid.len() <= y || self.grid[y].len() <= x {
-            return false;
+    fn draw_gate(window: &mut Window, tileset: &Image, gate_rec: Rectangle, position: &Position) {
+        let scale = Transform::scale(Vector::new(2, 2));
+        for offset in -1..2 {
+            window.draw_ex(
+                &Grid::to_rectangle(&position.add(0, offset)),
+                Img(&tileset.subimage(gate_rec)),
+                scale,
+                GATE_Z,
+            );
         }
-
-        match self.grid[y][x] {
-            Grid::Path => true && !self.gate.is_gate(x as u32, y as u32),
-            Grid::NonPath => false,
-            Grid::Empty => false,
-        }
-    }
-
-    fn to_grid(layers: Vec<tiled::Layer>) -> GridMap {
-        layers
-            .iter()
-            .map(|layer| {
-                layer
-                    .tiles
-                    .iter()
-                    .map(|row| {
-                        row.iter()
-                            .map(|tile| {
-                                if tile == &0 {
-                                    return Grid::Empty;
-                                }
-                                if PATH_SET.contains(tile) {
-                                    return Grid::Path;
-                                }
-                                Grid::NonPath
-                            })
-                            .collect()
-                    })
-                    .collect()
-            })
-            .fold(None, |a, b| match a {
-                None => Some(b),
-                Some(a) => Some(Self::join(a, b)),
-            })
-            .unwrap()
     }
 
-    fn join(a: GridMap, b: GridMap) -> GridMap {
-        let max_y = max(a.len(), b.len());
-        let new_grid: GridMap = (0..max_y)
-            .map(|y| {
-                let row_a = a.get(y);
-                let row_b = b.get(y);
-                let max_x = max(
-                    row_a.map(|v| v.len()).unwrap_or(0),
-                    row_b.map(|v| v.len()).unwrap_or(0),
-                );
-                (0..max_x)
-                    .map(|x| {
-                        let grid_a = row_a.and_then(|v| v.get(x)).unwrap_or(&Grid::Empty);
-                        let grid_b = row_b.and_then(|v| v.get(x)).unwrap_or(&Grid::Empty);
-                        match (grid_a, grid_b) {
-                            (Grid::Path, Grid::Empty) => Grid::Path,
-                            (Grid::Empty, Grid::Empty) => Grid::Empty,
-                            (Grid::Empty, Grid::Path) => Grid::Path,
-                            (Grid::Path, Grid::Path) => Grid::Path,
-                            (_, Grid::NonPath) => Grid::NonPath,
-                            (Grid::NonPath, _) => Grid::NonPath,
-                        }
-                    })
-                    .collect()
-            })
-            .collect();
-
-        new_grid
+    pub fn draw_debug(&self, window: &mut Window) {
+        for (y, row) in self.terrain.grid().iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let position = Position::new(x as u32, y as u32);
+                let area = Grid::to_cell(&position);
+                let color = match cell {
+                    Cell::Path => *debug_overlay::PATH,
+                    Cell::NonPath => *debug_overlay::NON_PATH,
+                    Cell::Empty => *debug_overlay::EMPTY,
+                };
+                DebugOverlay::tint(window, area, color);
+                if self.terrain.gate().is_gate(position.x, position.y) {
+                    DebugOverlay::outline(window, area, *debug_overlay::GATE);
+                }
+            }
+        }
     }
 
     fn to_game_layer(layer: &tiled::Layer, tile: &Dimension, image: &Dimension) -> GameLayer {
@@ -237,7 +213,7 @@ impl fmt::Debug for GameMap {
             writeln!(f, "{:?}", layer)?;
         }
 
-        for row in self.grid.iter() {
+        for row in self.terrain.grid().iter() {
             writeln!(f, "{:?}", row)?;
         }
 
@@ -1,19 +1,8 @@
-use constant;
-use grid::Grid;
-use primitive::{Dimension, Position};
-use quicksilver::{
-    geom::{Rectangle, Transform, Vector},
-    graphics::{Background::Img, Image},
-    lifecycle::Window,
-    Result,
-};
-
-const GATE_INDEX: u32 = 214;
+use primitive::Position;
 
 pub struct Gate {
     pub position: Position,
     state: State,
-    gate_rec: Rectangle,
 }
 
 #[derive(PartialEq)]
@@ -23,12 +12,10 @@ pub enum State {
 }
 
 impl Gate {
-    pub fn new(position: Position, tile: Dimension, image: Dimension) -> Self {
-        let gate_rec = Self::to_rectangle(GATE_INDEX, &tile, &image);
+    pub fn new(position: Position) -> Self {
         Gate {
             position,
             state: State::Closed,
-            gate_rec,
         }
     }
 
@@ -36,56 +23,47 @@ impl Gate {
         self.state = State::Opened;
     }
 
+    pub fn close(&mut self) {
+        self.state = State::Closed;
+    }
+
     pub fn is_gate(&self, x: u32, y: u32) -> bool {
-        self.is_close()
+        self.is_closed()
             && self.position.x == x
             && (self.position.y == y || (self.position.y + 1) == y || (self.position.y + 2) == y)
     }
 
-    fn is_close(&self) -> bool {
+    pub fn is_closed(&self) -> bool {
         match self.state {
             State::Opened => false,
             State::Closed => true,
         }
     }
+}
 
-    pub fn draw(&mut self, window: &mut Window, tileset: &Image) -> Result<()> {
-        if self.state == State::Closed {
-            let draw_rec = Grid::to_rectangle(&self.position.add(0, -1));
-            let scale = Transform::scale(Vector::new(2, 2));
-            window.draw_ex(
-                &draw_rec,
-                Img(&tileset.subimage(self.gate_rec)),
-                scale,
-                constant::GATE_Z,
-            );
-
-            let draw_rec = Grid::to_rectangle(&self.position);
-            window.draw_ex(
-                &draw_rec,
-                Img(&tileset.subimage(self.gate_rec)),
-                scale,
-                constant::GATE_Z,
-            );
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-            let draw_rec = Grid::to_rectangle(&self.position.add(0, 1));
-            window.draw_ex(
-                &draw_rec,
-                Img(&tileset.subimage(self.gate_rec)),
-                scale,
-                constant::GATE_Z,
-            );
-        }
-        Ok(())
+    #[test]
+    fn closed_gate_covers_three_cells_downwards() {
+        let gate = Gate::new(Position::new(4, 2));
+        assert!(!gate.is_gate(4, 1));
+        assert!(gate.is_gate(4, 2));
+        assert!(gate.is_gate(4, 3));
+        assert!(gate.is_gate(4, 4));
+        assert!(!gate.is_gate(4, 5));
+        assert!(!gate.is_gate(3, 2));
     }
 
-    fn to_rectangle(tile_index: u32, tile: &Dimension, image: &Dimension) -> Rectangle {
-        Rectangle::new(
-            (
-                ((tile_index % (image.width / tile.width) * tile.width) - tile.width) as f32,
-                (tile_index / (image.width / tile.height) * tile.height) as f32,
-            ),
-            (tile.width as f32, tile.height as f32),
-        )
+    #[test]
+    fn open_gate_blocks_nothing() {
+        let mut gate = Gate::new(Position::new(4, 2));
+        gate.open();
+        assert!(!gate.is_closed());
+        assert!(!gate.is_gate(4, 2));
+
+        gate.close();
+        assert!(gate.is_gate(4, 2));
     }
 }
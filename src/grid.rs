@@ -1,16 +1,12 @@
+use camera::Camera;
 use constant::{GRID_HEIGHT, GRID_WIDTH, TILE_HEIGHT, TILE_WIDTH, WALKING_DURATION};
 use direction::Direction;
+use display::Display;
 use player_state::PlayerState;
 use primitive::Position;
-use quicksilver::geom::{Rectangle, Vector};
-use std::fmt;
+use quicksilver::geom::{Rectangle, Shape, Vector};
 
-#[derive(Clone, Copy)]
-pub enum Grid {
-    Path,
-    NonPath,
-    Empty,
-}
+pub struct Grid;
 
 const COLLECTIBLE_X_OFFSET: u32 = TILE_WIDTH * 2;
 const COLLECTIBLE_Y_OFFSET: u32 = TILE_HEIGHT * 2;
@@ -40,13 +36,33 @@ impl Grid {
         )
     }
 
-    pub fn from_coordinate(coordinate: Vector) -> Position {
-        Position::new(
-            ((coordinate.x - GRID_X_OFFSET as f32) / GRID_WIDTH as f32) as u32,
-            ((coordinate.y - GRID_Y_OFFSET as f32) / GRID_HEIGHT as f32) as u32,
+    pub fn to_cell(position: &Position) -> Rectangle {
+        Rectangle::new(
+            (position.x * GRID_WIDTH, position.y * GRID_HEIGHT),
+            (GRID_WIDTH, GRID_HEIGHT),
         )
     }
 
+    pub fn from_coordinate(coordinate: Vector, camera: &Camera) -> Option<Position> {
+        if !Display::viewport().contains(coordinate) {
+            return None;
+        }
+
+        let coordinate = coordinate + camera.offset() - Vector::new(GRID_X_OFFSET, GRID_Y_OFFSET);
+        if coordinate.x < 0. || coordinate.y < 0. {
+            return None;
+        }
+
+        Some(Position::new(
+            (coordinate.x / GRID_WIDTH as f32) as u32,
+            (coordinate.y / GRID_HEIGHT as f32) as u32,
+        ))
+    }
+
+    pub fn to_map_size(columns: usize, rows: usize) -> Vector {
+        Vector::new(columns as u32 * GRID_WIDTH, rows as u32 * GRID_HEIGHT)
+    }
+
     pub fn to_player_coordinate(state: &PlayerState, position: &Position) -> Vector {
         let (delta_x, delta_y) = match state {
             PlayerState::Walking {
@@ -90,13 +106,3 @@ impl Grid {
         )
     }
 }
-
-impl fmt::Debug for Grid {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Grid::Empty => write!(f, " "),
-            Grid::NonPath => write!(f, "#"),
-            Grid::Path => write!(f, "="),
-        }
-    }
-}
@@ -0,0 +1,259 @@
+use constant::{HUD_Z, WINDOW_WIDTH};
+use display::Display;
+use locale::{Language, Message};
+use quicksilver::{
+    geom::{Rectangle, Shape, Transform, Triangle, Vector},
+    graphics::{Background::Col, Background::Img, Color},
+    input::{ButtonState, Key, MouseButton},
+    lifecycle::Window,
+    Result,
+};
+use text::{Text, TextStyle};
+
+const BUTTON_SIZE: f32 = 40.;
+const MARGIN: f32 = 8.;
+const REPLAY_SEGMENTS: u32 = 12;
+
+lazy_static! {
+    static ref LABEL: TextStyle = TextStyle::new(24.0, Color::WHITE);
+    static ref BUTTON: Color = Color::BLACK.with_alpha(0.6);
+    static ref HOVER: Color = Color::WHITE.with_alpha(0.3);
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Button {
+    Mute,
+    Replay,
+    Pause,
+}
+
+const BUTTONS: [Button; 3] = [Button::Mute, Button::Replay, Button::Pause];
+
+pub struct Hud {
+    language: Language,
+    level: u32,
+    score: u32,
+    muted: bool,
+    paused: bool,
+    hover: Option<Button>,
+    click: Option<Vector>,
+}
+
+impl Hud {
+    pub fn new() -> Self {
+        Hud {
+            language: Language::English,
+            level: 0,
+            score: 0,
+            muted: false,
+            paused: false,
+            hover: None,
+            click: None,
+        }
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+    }
+
+    pub fn set_status(&mut self, level: u32, score: u32, muted: bool, paused: bool) {
+        self.level = level;
+        self.score = score;
+        self.muted = muted;
+        self.paused = paused;
+    }
+
+    pub fn click(&self) -> Option<Vector> {
+        self.click
+    }
+
+    pub fn update(&mut self, window: &Window) -> Option<Button> {
+        let pointer = Display::pointer(window);
+        self.hover = Self::button_at(pointer);
+        self.click = None;
+
+        if window.mouse()[MouseButton::Left] == ButtonState::Released {
+            match self.hover {
+                Some(button) => return Some(button),
+                None => self.click = Some(pointer),
+            }
+        }
+
+        let keyboard = window.keyboard();
+        if keyboard[Key::M] == ButtonState::Pressed {
+            Some(Button::Mute)
+        } else if keyboard[Key::R] == ButtonState::Pressed {
+            Some(Button::Replay)
+        } else if keyboard[Key::P] == ButtonState::Pressed {
+            Some(Button::Pause)
+        } else {
+            None
+        }
+    }
+
+    pub fn draw(&mut self, window: &mut Window, text: &mut Text) -> Result<()> {
+        let status = format!(
+            "{} {}   {} {}",
+            self.language.text(Message::Level),
+            self.level + 1,
+            self.language.text(Message::Score),
+            self.score
+        );
+        if let Some(status_text) = text.render(&status, &LABEL)? {
+            let area = status_text.area();
+            window.draw_ex(
+                &Rectangle::new(
+                    (MARGIN, MARGIN),
+                    area.size() + Vector::new(MARGIN, MARGIN) * 2,
+                ),
+                Col(*BUTTON),
+                Transform::IDENTITY,
+                HUD_Z,
+            );
+            window.draw_ex(
+                &area.translate((MARGIN * 2., MARGIN * 2.)),
+                Img(&status_text),
+                Transform::IDENTITY,
+                HUD_Z + 1,
+            );
+        }
+
+        for button in BUTTONS.iter() {
+            let area = Self::area(*button);
+            let background = match self.hover {
+                Some(hover) if hover == *button => *HOVER,
+                _ => *BUTTON,
+            };
+            window.draw_ex(&area, Col(background), Transform::IDENTITY, HUD_Z);
+            self.draw_icon(window, *button, area);
+        }
+
+        Ok(())
+    }
+
+    fn draw_icon(&self, window: &mut Window, button: Button, area: Rectangle) {
+        let center = area.center();
+        let unit = BUTTON_SIZE / 8.;
+        let icon = Col(Color::WHITE);
+        match button {
+            Button::Mute => {
+                window.draw_ex(
+                    &Rectangle::new(
+                        center - Vector::new(unit * 3., unit),
+                        (unit * 2., unit * 2.),
+                    ),
+                    icon,
+                    Transform::IDENTITY,
+                    HUD_Z + 1,
+                );
+                window.draw_ex(
+                    &Triangle::new(
+                        center - Vector::new(unit * 2., 0.),
+                        center + Vector::new(unit, -unit * 2.5),
+                        center + Vector::new(unit, unit * 2.5),
+                    ),
+                    icon,
+                    Transform::IDENTITY,
+                    HUD_Z + 1,
+                );
+                if self.muted {
+                    let stroke = Rectangle::new(
+                        center - Vector::new(unit * 3., unit / 2.),
+                        (unit * 6., unit),
+                    );
+                    for angle in [45, -45].iter() {
+                        window.draw_ex(
+                            &stroke,
+                            Col(Color::RED),
+                            Transform::rotate(*angle),
+                            HUD_Z + 2,
+                        );
+                    }
+                }
+            }
+            Button::Replay => {
+                let (inner, outer) = (unit * 1.5, unit * 2.5);
+                let step = 270. / REPLAY_SEGMENTS as f32;
+                for segment in 0..REPLAY_SEGMENTS {
+                    let from = Vector::from_angle(segment as f32 * step);
+                    let to = Vector::from_angle((segment + 1) as f32 * step);
+                    for triangle in [
+                        Triangle::new(
+                            center + from * inner,
+                            center + from * outer,
+                            center + to * outer,
+                        ),
+                        Triangle::new(
+                            center + from * inner,
+                            center + to * outer,
+                            center + to * inner,
+                        ),
+                    ]
+                    .iter()
+                    {
+                        window.draw_ex(triangle, icon, Transform::IDENTITY, HUD_Z + 1);
+                    }
+                }
+
+                let tip = center + Vector::new(0., -unit * 2.);
+                window.draw_ex(
+                    &Triangle::new(
+                        tip + Vector::new(0., -unit * 1.5),
+                        tip + Vector::new(unit * 1.5, 0.),
+                        tip + Vector::new(0., unit * 1.5),
+                    ),
+                    icon,
+                    Transform::IDENTITY,
+                    HUD_Z + 1,
+                );
+            }
+            Button::Pause if self.paused => {
+                window.draw_ex(
+                    &Triangle::new(
+                        center - Vector::new(unit * 1.5, unit * 2.),
+                        center + Vector::new(unit * 2., 0.),
+                        center - Vector::new(unit * 1.5, -unit * 2.),
+                    ),
+                    icon,
+                    Transform::IDENTITY,
+                    HUD_Z + 1,
+                );
+            }
+            Button::Pause => {
+                for offset in [-1.5, 0.5].iter() {
+                    window.draw_ex(
+                        &Rectangle::new(
+                            center + Vector::new(unit * offset, -unit * 2.),
+                            (unit, unit * 4.),
+                        ),
+                        icon,
+                        Transform::IDENTITY,
+                        HUD_Z + 1,
+                    );
+                }
+            }
+        }
+    }
+
+    fn button_at(point: Vector) -> Option<Button> {
+        BUTTONS
+            .iter()
+            .find(|button| Self::area(**button).contains(point))
+            .cloned()
+    }
+
+    fn area(button: Button) -> Rectangle {
+        let index = match button {
+            Button::Mute => 2.,
+            Button::Replay => 1.,
+            Button::Pause => 0.,
+        };
+        Rectangle::new(
+            (
+                WINDOW_WIDTH as f32 - (BUTTON_SIZE + MARGIN) * (index + 1.),
+                MARGIN,
+            ),
+            (BUTTON_SIZE, BUTTON_SIZE),
+        )
+    }
+}
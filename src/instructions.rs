@@ -0,0 +1,118 @@
+use assets::{Assets, Handle};
+use audio::Audio;
+use clip::Clip;
+use constant;
+use locale::{Language, Message};
+use puzzle::Puzzle;
+use quicksilver::{
+    geom::{Shape, Transform, Vector},
+    graphics::{Background::Img, Color},
+    lifecycle::Window,
+    Result,
+};
+use text::{Text, TextStyle};
+
+lazy_static! {
+    static ref BIG: TextStyle = TextStyle::new(42.0, Color::WHITE);
+    static ref NORMAL: TextStyle = TextStyle::new(24.0, Color::WHITE);
+}
+
+pub struct Instructions {
+    language: Language,
+    tick: f64,
+    instruction_sound: Handle<Option<Clip>>,
+    answer_sound: Handle<Option<Clip>>,
+    instruction_played_timestamp: f64,
+    word_played_timestamp: f64,
+}
+
+impl Instructions {
+    pub fn instruction_sound(language: Language) -> Vec<String> {
+        language.sounds("instructions")
+    }
+
+    pub fn word_sound(word: &str, language: Language) -> Vec<String> {
+        language.sounds(&word.to_ascii_lowercase())
+    }
+
+    pub fn new(word: &str, language: Language, assets: &Assets) -> Self {
+        Instructions {
+            language,
+            tick: 0.,
+            instruction_sound: assets.voice(&Self::instruction_sound(language)),
+            answer_sound: assets.voice(&Self::word_sound(word, language)),
+            instruction_played_timestamp: 0.,
+            word_played_timestamp: 0.,
+        }
+    }
+
+    pub fn update(&mut self, elapsed: f64, puzzle: &Puzzle, audio: &mut Audio) -> Result<()> {
+        self.tick += elapsed;
+        if self.instruction_played_timestamp <= 0.
+            && audio.play_voice(
+                &self.instruction_sound,
+                self.language.text(Message::InstructionCaption),
+            )?
+        {
+            self.instruction_played_timestamp = self.tick;
+        }
+
+        if self.instruction_played_timestamp > 0.
+            && self.word_played_timestamp <= 0.
+            && audio.play_voice(&self.answer_sound, &puzzle.word())?
+        {
+            self.word_played_timestamp = self.tick;
+        }
+
+        Ok(())
+    }
+
+    pub fn reset(&mut self) {
+        self.tick = 0.;
+        self.instruction_played_timestamp = 0.;
+        self.word_played_timestamp = 0.;
+    }
+
+    pub fn replay_word(&mut self, puzzle: &Puzzle, audio: &mut Audio) -> Result<()> {
+        audio.stop_voice();
+        if audio.play_voice(&self.answer_sound, &puzzle.word())? {
+            self.word_played_timestamp = self.tick;
+        }
+
+        Ok(())
+    }
+
+    pub fn draw(&mut self, window: &mut Window, text: &mut Text, puzzle: &Puzzle) -> Result<()> {
+        let instruction_text = text.render(self.language.text(Message::Instruction), &NORMAL)?;
+        let answer_text = text.render(&puzzle.rendered(), &BIG)?;
+        if let (Some(instruction_text), Some(answer_text)) = (instruction_text, answer_text) {
+            let instruction_height = instruction_text.area().height() as u32;
+            let answer_height = answer_text.area().height() as u32;
+            let instruction_area = instruction_text.area().with_center((
+                constant::WINDOW_WIDTH / 2,
+                constant::WINDOW_HEIGHT - (instruction_height + answer_height),
+            ));
+            let answer_area = answer_text.area().with_center((
+                constant::WINDOW_WIDTH / 2,
+                constant::WINDOW_HEIGHT - (answer_height * 2 / 3),
+            ));
+            text.backdrop(window, instruction_area, 3);
+            text.backdrop(window, answer_area, 3);
+            window.draw_ex(
+                &instruction_area,
+                Img(&instruction_text),
+                Transform::scale(Vector::new(1, 1)),
+                4,
+            );
+
+            window.draw_ex(
+                &answer_area,
+                Img(&answer_text),
+                Transform::scale(Vector::new(1, 1)),
+                4,
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -1,110 +1,282 @@
+use accessibility::FONT_FAMILIES;
+use assets::{Assets, Handle, Pending, Resource};
+use audio::Audio;
+use camera::Camera;
+use clock::{self, Clock, STEP};
 use collectible::Collectible;
+use config::Config;
+use constant::COLLECTIBLE_Z;
+use debug_overlay::{self, DebugOverlay};
+use display::Display;
+use event_log::{Activity, EventLog};
 use game_map::GameMap;
-use gate::Gate;
+use grid::Grid;
+use instructions::Instructions;
+use level_spec::LevelSpec;
+use locale::{Language, Message};
+use music::DEFAULT_TRACK;
 use player::Player;
 use primitive::Position;
-use puzzle::{CanCollect, Puzzle};
 use quicksilver::{
-    lifecycle::{Asset, Window},
+    geom::{Shape, Transform, Vector},
+    graphics::{Background::Img, Color},
+    lifecycle::Window,
     Result,
 };
+use recording::{Outcome, Recording, Replay, Session};
+use simulation::{Event, Simulation};
+use sound_effects::{SoundEffect, SoundEffects};
+use text::{Text, TextStyle};
 
-pub struct Level {
-    index: u32,
-    pub start_position: Position,
-    game_map: Asset<GameMap>,
-    puzzle: Puzzle,
-    collectible: Vec<Collectible>,
+const LETTER_CAPTION_DURATION: f64 = 1000.;
+const FEEDBACK_CAPTION_DURATION: f64 = 2000.;
+
+lazy_static! {
+    static ref LETTER: TextStyle = TextStyle::new(24.0, Color::WHITE);
 }
 
-pub enum Solved {
-    Yes,
-    No,
+pub struct Level {
+    index: u32,
+    language: Language,
+    game_map: Handle<GameMap>,
+    simulation: Simulation,
+    instructions: Instructions,
+    camera: Camera,
+    clock: Clock,
+    target: Option<Position>,
+    previous: Option<(Vector, Vector)>,
+    recording: Recording,
+    replay: Option<Replay>,
+    resources: Vec<Box<dyn Pending>>,
+    music: Option<&'static str>,
+    sound_effects: SoundEffects,
 }
 
 impl Level {
-    pub fn start() -> Level {
-        Self::level1()
+    pub fn new(index: u32, word: &str, language: Language, assets: &Assets) -> Option<Level> {
+        let spec = LevelSpec::get(index)?.with_word(word)?;
+        let game_map = Handle::load(
+            spec.map,
+            GameMap::load(spec.map, spec.gate_position.clone(), assets),
+        );
+        Some(Level {
+            index,
+            language,
+            game_map,
+            simulation: spec.simulation(),
+            instructions: Instructions::new(&spec.word, language, assets),
+            camera: Camera::new(),
+            clock: Clock::new(),
+            target: None,
+            previous: None,
+            recording: Recording::new(index, &spec.word),
+            replay: None,
+            resources: Self::preload(&spec.word, language, spec.music, spec.theme, assets),
+            music: spec.music,
+            sound_effects: SoundEffects::new(spec.theme, assets),
+        })
     }
 
-    fn new(level: u32) -> Option<Level> {
-        match level {
-            0 => Some(Self::level1()),
-            1 => Some(Self::level2()),
-            _ => None,
-        }
+    pub fn replaying(mut self, recording: &Recording) -> Level {
+        self.recording = Recording::new(recording.level, &recording.word);
+        self.replay = Some(recording.replay());
+        self
     }
 
-    pub fn next_level(&self) -> Option<Level> {
-        Self::new(self.index + 1)
-    }
-
-    fn level2() -> Self {
-        let game_map = Asset::new(GameMap::load(
-            "resources/tiled/level2.tmx",
-            Position::new(24, 14),
-        ));
-        let puzzle = Puzzle::new("JONATHAN".to_string());
-        let collectible = vec![
-            Collectible::new('J', Position::new(5, 14)),
-            Collectible::new('O', Position::new(7, 14)),
-            Collectible::new('N', Position::new(8, 14)),
-            Collectible::new('A', Position::new(15, 14)),
-            Collectible::new('T', Position::new(18, 14)),
-            Collectible::new('H', Position::new(20, 14)),
-            Collectible::new('A', Position::new(21, 14)),
-            Collectible::new('N', Position::new(22, 14)),
+    fn preload(
+        word: &str,
+        language: Language,
+        music: Option<&str>,
+        theme: &str,
+        assets: &Assets,
+    ) -> Vec<Box<dyn Pending>> {
+        let mut resources = vec![
+            Resource::Track(music.unwrap_or(DEFAULT_TRACK).to_string()),
+            Resource::Voice(Instructions::instruction_sound(language)),
+            Resource::Voice(Instructions::word_sound(word, language)),
         ];
+        resources.extend(
+            FONT_FAMILIES
+                .iter()
+                .map(|family| Resource::Font(family.path().to_string())),
+        );
+        resources.extend(Player::resources());
+        resources.extend(SoundEffects::resources(theme));
+        assets.preload(&resources)
+    }
 
-        Level {
-            index: 1,
-            start_position: Position::new(0, 14),
-            game_map,
-            puzzle,
-            collectible,
+    pub fn title(&self) -> String {
+        format!(
+            "{} {}: {}",
+            self.language.text(Message::Level),
+            self.index + 1,
+            self.simulation.puzzle.word()
+        )
+    }
+
+    pub fn music(&self) -> &str {
+        self.music.unwrap_or(DEFAULT_TRACK)
+    }
+
+    pub fn progress(&self) -> Result<(usize, usize)> {
+        let mut tileset_image = None;
+        self.game_map.execute(|game_map| {
+            tileset_image = Some(game_map.tileset_image().clone());
+            Ok(())
+        })?;
+
+        let mut pending: Vec<&dyn Pending> = self.resources.iter().map(|r| r.as_ref()).collect();
+        pending.push(&self.game_map);
+        if let Some(ref tileset_image) = tileset_image {
+            pending.push(tileset_image);
+        }
+
+        let mut loaded = 0;
+        let mut total = 0;
+        for resource in pending {
+            total += 1;
+            if resource.is_loaded()? {
+                loaded += 1;
+            }
         }
+
+        Ok((loaded, total))
     }
 
-    fn level1() -> Self {
-        let game_map = Asset::new(GameMap::load(
-            "resources/tiled/level1.tmx",
-            Position::new(24, 14),
-        ));
-        let puzzle = Puzzle::new("APPLE".to_string());
-        let collectible = vec![
-            Collectible::new('A', Position::new(5, 7)),
-            Collectible::new('P', Position::new(10, 12)),
-            Collectible::new('P', Position::new(18, 7)),
-            Collectible::new('L', Position::new(17, 11)),
-            Collectible::new('E', Position::new(22, 11)),
-        ];
+    pub fn index(&self) -> u32 {
+        self.index
+    }
 
-        Level {
-            index: 0,
-            start_position: Position::new(0, 14),
-            game_map,
-            puzzle,
-            collectible,
+    pub fn score(&self) -> u32 {
+        self.simulation.score()
+    }
+
+    pub fn log(&self, event_log: &mut EventLog, activity: Activity) {
+        if self.replay.is_none() {
+            event_log.log(self.index, &self.simulation.puzzle.word(), activity);
+        }
+    }
+
+    pub fn configure(&mut self, config: &Config, assets: &Assets) {
+        if config.language != self.language {
+            self.language = config.language;
+            self.instructions =
+                Instructions::new(&self.simulation.puzzle.word(), self.language, assets);
         }
+
+        if self.replay.is_none() {
+            self.simulation
+                .movement
+                .set_walking_speed(config.walking_speed as f64);
+        }
+    }
+
+    pub fn session(&self) -> Option<Session> {
+        let mut session = None;
+        let simulation = &self.simulation;
+        let recording = &self.recording;
+        let _ = self.game_map.execute(|game_map| {
+            session = Some(Session {
+                recording: recording.clone(),
+                outcome: Outcome::new(simulation, game_map.terrain()),
+            });
+            Ok(())
+        });
+
+        session
+    }
+
+    pub fn resume(&mut self) {
+        self.clock.reset();
     }
 
-    pub fn update(&mut self, window: &mut Window, player: &mut Player) -> Result<()> {
-        let collectibles = &mut self.collectible;
-        let puzzle = &mut self.puzzle;
+    pub fn restart(&mut self) -> Result<()> {
+        self.clock.reset();
+        self.instructions.reset();
+        self.camera = Camera::new();
+        self.target = None;
+        self.previous = None;
+        self.recording = Recording::new(self.index, &self.simulation.puzzle.word());
+        self.replay = None;
+        let simulation = &mut self.simulation;
         self.game_map.execute(|game_map| {
-            player.update(window, game_map)?;
-            for collectible in collectibles.into_iter() {
-                if collectible.collide_with(player) {
-                    match puzzle.collect(collectible.letter) {
-                        CanCollect::Yes => collectible.collect(),
-                        CanCollect::No => {}
+            simulation.restart(game_map.terrain_mut());
+            Ok(())
+        })
+    }
+
+    pub fn replay_word(&mut self, audio: &mut Audio) -> Result<()> {
+        self.instructions
+            .replay_word(&self.simulation.puzzle, audio)
+    }
+
+    pub fn update(
+        &mut self,
+        window: &mut Window,
+        player: &mut Player,
+        audio: &mut Audio,
+        event_log: &mut EventLog,
+        click: Option<Vector>,
+    ) -> Result<()> {
+        let elapsed = window.update_rate();
+        let mut input = player.input(window, &self.camera, click);
+        if input.target.is_none() {
+            input.target = self.target.take();
+        }
+
+        self.sound_effects.update(elapsed);
+        let steps = self.clock.advance(clock::now());
+        if steps == 0 {
+            self.target = input.target;
+            return Ok(());
+        }
+
+        let index = self.index;
+        let language = self.language;
+        let word = self.simulation.puzzle.word();
+        let simulation = &mut self.simulation;
+        let instructions = &mut self.instructions;
+        let camera = &mut self.camera;
+        let previous = &mut self.previous;
+        let recording = &mut self.recording;
+        let replay = &mut self.replay;
+        let sound_effects = &mut self.sound_effects;
+        self.game_map.execute(|game_map| {
+            for _ in 0..steps {
+                *previous = Some((Player::coordinate(&simulation.movement), camera.offset()));
+                let step_input = match replay.as_mut().map(|replay| replay.next()) {
+                    Some(Some((recorded, walking_speed))) => {
+                        simulation.movement.set_walking_speed(walking_speed);
+                        recorded
                     }
+                    Some(None) => {
+                        *replay = None;
+                        audio.caption(
+                            language.text(Message::ReplayFinished),
+                            FEEDBACK_CAPTION_DURATION,
+                        );
+                        input.clone()
+                    }
+                    None => input.clone(),
+                };
+                recording.record(&step_input, simulation.movement.walking_speed());
+                for event in simulation.step(&step_input, STEP, game_map.terrain_mut()) {
+                    if replay.is_none() {
+                        if let Some(activity) = Self::activity(&event) {
+                            event_log.log(index, &word, activity);
+                        }
+                    }
+                    Self::play_event(event, language, sound_effects, audio)?;
                 }
-            }
+                input.target = None;
 
-            puzzle.update(window)?;
-            if puzzle.is_solved() {
-                game_map.open_gate();
+                player.animate(STEP, &simulation.movement);
+                camera.follow(
+                    Player::coordinate(&simulation.movement),
+                    game_map.size(),
+                    STEP,
+                );
+                instructions.update(STEP, &simulation.puzzle, audio)?;
             }
 
             Ok(())
@@ -113,28 +285,127 @@ impl Level {
         Ok(())
     }
 
-    pub fn passing_the_gate(&mut self, player: &Player) -> bool {
+    fn activity(event: &Event) -> Option<Activity> {
+        match *event {
+            Event::Collected(letter, slot) => Some(Activity::LetterCollected { letter, slot }),
+            Event::Rejected(letter, slot) => Some(Activity::WrongLetter { letter, slot }),
+            Event::GateOpened => Some(Activity::GateOpened),
+            Event::Footstep | Event::Bump => None,
+        }
+    }
+
+    fn play_event(
+        event: Event,
+        language: Language,
+        sound_effects: &mut SoundEffects,
+        audio: &mut Audio,
+    ) -> Result<()> {
+        match event {
+            Event::Footstep => sound_effects.play(SoundEffect::Footstep, audio)?,
+            Event::Bump => sound_effects.play(SoundEffect::Bump, audio)?,
+            Event::Collected(letter, _) => {
+                sound_effects.play(SoundEffect::Pickup, audio)?;
+                audio.caption(&format!("{}!", letter), LETTER_CAPTION_DURATION);
+            }
+            Event::Rejected(..) => {
+                sound_effects.play(SoundEffect::Bump, audio)?;
+                audio.caption(language.text(Message::NotYet), FEEDBACK_CAPTION_DURATION);
+            }
+            Event::GateOpened => {
+                sound_effects.play(SoundEffect::GateOpen, audio)?;
+                audio.caption(language.text(Message::GateOpen), FEEDBACK_CAPTION_DURATION);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn passing_the_gate(&mut self) -> bool {
         let mut passing_the_gate = false;
+        let simulation = &self.simulation;
         self.game_map.execute(|game_map| {
-            passing_the_gate = game_map.gate_position() == &player.position;
+            passing_the_gate = simulation.is_at_gate(game_map.terrain());
             Ok(())
         });
 
         passing_the_gate
     }
 
-    pub fn draw(&mut self, window: &mut Window, player: &mut Player) -> Result<()> {
+    pub fn draw(
+        &mut self,
+        window: &mut Window,
+        player: &mut Player,
+        display: &Display,
+        debug: &mut DebugOverlay,
+        text: &mut Text,
+    ) -> Result<()> {
+        let debug_enabled = debug.is_enabled();
+        let simulation = &self.simulation;
+        let coordinate = Player::coordinate(&simulation.movement);
+        let offset = self.camera.offset();
+        let (coordinate, offset) = match self.previous {
+            Some((previous_coordinate, previous_offset)) => {
+                let alpha = self.clock.alpha(clock::now()) as f32;
+                (
+                    previous_coordinate + (coordinate - previous_coordinate) * alpha,
+                    previous_offset + (offset - previous_offset) * alpha,
+                )
+            }
+            None => (coordinate, offset),
+        };
         self.game_map.execute(|game_map| {
+            window.set_view(display.view(offset));
             game_map.draw(window)?;
-            player.draw(window)?;
+            player.draw(window, &simulation.movement, coordinate)?;
+            for collectible in simulation.collectibles.iter() {
+                Self::draw_collectible(window, text, collectible)?;
+            }
+
+            if debug_enabled {
+                game_map.draw_debug(window);
+                for collectible in simulation.collectibles.iter() {
+                    if !collectible.is_collected() {
+                        let cell = Grid::to_cell(&collectible.position);
+                        DebugOverlay::outline(window, cell, *debug_overlay::COLLECTIBLE);
+                    }
+                }
+            }
+
+            window.flush()?;
+            window.set_view(display.view(Vector::ZERO));
             Ok(())
         })?;
 
-        self.puzzle.draw(window)?;
+        self.instructions
+            .draw(window, text, &self.simulation.puzzle)?;
+        let tile = Grid::from_coordinate(Display::pointer(window), &self.camera);
+        debug.draw(window, text, tile)?;
+
+        Ok(())
+    }
+
+    fn draw_collectible(
+        window: &mut Window,
+        text: &mut Text,
+        collectible: &Collectible,
+    ) -> Result<()> {
+        if collectible.is_collected() {
+            return Ok(());
+        }
+
+        let mut letter = [0; 4];
+        let letter = collectible.letter.encode_utf8(&mut letter);
+        if let Some(word_text) = text.render(letter, &LETTER)? {
+            let rectangle = Grid::to_collectible_coordinate(&collectible.position);
 
-        let collectible = &mut self.collectible;
-        for c in collectible.into_iter() {
-            c.draw(window)?;
+            let area = word_text.area().with_center(rectangle.pos);
+            text.backdrop(window, area, COLLECTIBLE_Z - 1);
+            window.draw_ex(
+                &area,
+                Img(&word_text),
+                Transform::scale(Vector::new(1, 1)),
+                COLLECTIBLE_Z,
+            );
         }
 
         Ok(())
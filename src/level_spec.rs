@@ -0,0 +1,158 @@
+use collectible::Collectible;
+use primitive::Position;
+use simulation::Simulation;
+
+pub struct LevelSpec {
+    pub map: &'static str,
+    pub word: String,
+    pub start_position: Position,
+    pub gate_position: Position,
+    pub slots: Vec<Position>,
+    pub music: Option<&'static str>,
+    pub theme: &'static str,
+}
+
+impl LevelSpec {
+    pub fn get(index: u32) -> Option<LevelSpec> {
+        match index {
+            0 => Some(Self::level1()),
+            1 => Some(Self::level2()),
+            _ => None,
+        }
+    }
+
+    pub fn fits(&self, word: &str) -> bool {
+        !word.is_empty() && word.chars().count() <= self.slots.len()
+    }
+
+    pub fn with_word(self, word: &str) -> Option<Self> {
+        if !self.fits(word) {
+            return None;
+        }
+
+        Some(LevelSpec {
+            word: word.to_string(),
+            ..self
+        })
+    }
+
+    pub fn letters(&self) -> Vec<(char, Position)> {
+        self.word.chars().zip(self.slots.iter().cloned()).collect()
+    }
+
+    pub fn simulation(&self) -> Simulation {
+        let collectibles = self
+            .letters()
+            .into_iter()
+            .map(|(letter, position)| Collectible::new(letter, position))
+            .collect();
+        Simulation::new(&self.word, collectibles, self.start_position.clone())
+    }
+
+    fn level1() -> Self {
+        LevelSpec {
+            map: "resources/tiled/level1.tmx",
+            word: "APPLE".to_string(),
+            start_position: Position::new(0, 14),
+            gate_position: Position::new(24, 14),
+            slots: vec![
+                Position::new(5, 7),
+                Position::new(10, 12),
+                Position::new(18, 7),
+                Position::new(17, 11),
+                Position::new(22, 11),
+            ],
+            music: None,
+            theme: "meadow",
+        }
+    }
+
+    fn level2() -> Self {
+        LevelSpec {
+            map: "resources/tiled/level2.tmx",
+            word: "JONATHAN".to_string(),
+            start_position: Position::new(0, 14),
+            gate_position: Position::new(24, 14),
+            slots: vec![
+                Position::new(5, 14),
+                Position::new(7, 14),
+                Position::new(8, 14),
+                Position::new(15, 14),
+                Position::new(18, 14),
+                Position::new(20, 14),
+                Position::new(21, 14),
+                Position::new(22, 14),
+            ],
+            music: Some("resources/music/adventure"),
+            theme: "castle",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use music::DEFAULT_TRACK;
+    use sound_format;
+
+    fn levels() -> Vec<LevelSpec> {
+        (0..)
+            .map(LevelSpec::get)
+            .take_while(Option::is_some)
+            .map(Option::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn levels_progress_in_order_and_end() {
+        let words: Vec<String> = levels().into_iter().map(|level| level.word).collect();
+        assert_eq!(words, vec!["APPLE", "JONATHAN"]);
+        assert!(LevelSpec::get(2).is_none());
+    }
+
+    #[test]
+    fn every_level_has_the_letters_for_its_word() {
+        for level in levels() {
+            let mut simulation = level.simulation();
+            for (letter, _) in level.letters() {
+                simulation.puzzle.collect(letter);
+            }
+            assert!(simulation.puzzle.is_solved(), "{}", level.word);
+        }
+    }
+
+    #[test]
+    fn words_are_laid_out_on_the_first_slots() {
+        let level = LevelSpec::get(1).unwrap().with_word("APPLE").unwrap();
+        let letters = level.letters();
+        assert_eq!(letters.len(), 5);
+        assert_eq!(letters[0], ('A', level.slots[0].clone()));
+        assert_eq!(letters[4], ('E', level.slots[4].clone()));
+
+        assert!(LevelSpec::get(0).unwrap().with_word("JONATHAN").is_none());
+        assert!(LevelSpec::get(0).unwrap().with_word("").is_none());
+    }
+
+    #[test]
+    fn slots_do_not_share_a_cell() {
+        for level in levels() {
+            for (index, position) in level.slots.iter().enumerate() {
+                assert!(level.slots[index + 1..]
+                    .iter()
+                    .all(|other| other != position));
+                assert_ne!(position, &level.start_position);
+                assert_ne!(position, &level.gate_position);
+            }
+        }
+    }
+
+    #[test]
+    fn every_music_track_is_on_disk() {
+        assert!(sound_format::is_on_disk(DEFAULT_TRACK));
+        for level in levels() {
+            if let Some(music) = level.music {
+                assert!(sound_format::is_on_disk(music), "{}", music);
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use constant::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use level::Level;
+use playing::Playing;
+use quicksilver::{
+    geom::{Rectangle, Transform, Vector},
+    graphics::{Background::Col, Color},
+    lifecycle::Window,
+    Result,
+};
+use scene::{Context, Scene, SceneChange};
+
+const BAR_WIDTH: f32 = 400.;
+const BAR_HEIGHT: f32 = 24.;
+const BORDER: f32 = 4.;
+
+pub struct Loading {
+    loaded: usize,
+    total: usize,
+    level: Option<Level>,
+    score: u32,
+}
+
+impl Loading {
+    pub fn new(level: Level, score: u32, context: &mut Context) -> Self {
+        context.music.play(level.music());
+        context.text.clear_cache();
+        Loading {
+            loaded: 0,
+            total: 0,
+            level: Some(level),
+            score,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.total > 0 && self.loaded == self.total
+    }
+}
+
+impl Scene for Loading {
+    fn update(&mut self, _window: &mut Window, context: &mut Context) -> Result<SceneChange> {
+        if let Some(ref level) = self.level {
+            let (loaded, total) = level.progress()?;
+            self.loaded = loaded;
+            self.total = total;
+        }
+
+        if !self.is_done() {
+            return Ok(SceneChange::Stay);
+        }
+
+        match self.level.take() {
+            Some(level) => Ok(SceneChange::Replace(Box::new(Playing::new(
+                level, self.score, context,
+            )))),
+            None => Ok(SceneChange::Stay),
+        }
+    }
+
+    fn updates_during_transition(&self) -> bool {
+        true
+    }
+
+    fn draw(&mut self, window: &mut Window, _context: &mut Context) -> Result<()> {
+        let progress = match self.total {
+            0 => 0.,
+            total => self.loaded as f32 / total as f32,
+        };
+        let position = Vector::new(
+            (WINDOW_WIDTH as f32 - BAR_WIDTH) / 2.,
+            (WINDOW_HEIGHT as f32 - BAR_HEIGHT) / 2.,
+        );
+        let border = Vector::new(BORDER, BORDER);
+
+        window.draw(
+            &Rectangle::new(
+                position - border,
+                Vector::new(BAR_WIDTH, BAR_HEIGHT) + border * 2,
+            ),
+            Col(Color::WHITE),
+        );
+        window.draw_ex(
+            &Rectangle::new(position, (BAR_WIDTH, BAR_HEIGHT)),
+            Col(Color::BLACK),
+            Transform::IDENTITY,
+            1,
+        );
+        window.draw_ex(
+            &Rectangle::new(position, (BAR_WIDTH * progress, BAR_HEIGHT)),
+            Col(Color::WHITE),
+            Transform::IDENTITY,
+            2,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Indonesian,
+}
+
+pub const LANGUAGES: [Language; 2] = [Language::English, Language::Indonesian];
+
+impl Language {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Indonesian => "id",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Indonesian => "Bahasa Indonesia",
+        }
+    }
+
+    pub fn cycle(&self, delta: i32) -> Language {
+        let index = LANGUAGES
+            .iter()
+            .position(|language| language == self)
+            .unwrap_or(0) as i32;
+        let len = LANGUAGES.len() as i32;
+        LANGUAGES[(((index + delta) % len + len) % len) as usize]
+    }
+
+    pub fn text(&self, message: Message) -> &'static str {
+        self.table()
+            .iter()
+            .find(|(translated, _)| *translated == message)
+            .map(|(_, text)| *text)
+            .unwrap_or_else(|| message.english())
+    }
+
+    pub fn sounds(&self, name: &str) -> Vec<String> {
+        let mut languages = vec![*self];
+        if *self != Language::English {
+            languages.push(Language::English);
+        }
+        languages
+            .iter()
+            .map(|language| format!("resources/sounds/{}/{}", language.code(), name))
+            .collect()
+    }
+
+    fn table(&self) -> &'static [(Message, &'static str)] {
+        match self {
+            Language::English => &[],
+            Language::Indonesian => &INDONESIAN,
+        }
+    }
+}
+
+const INDONESIAN: [(Message, &str); 30] = [
+    (Message::Instruction, "Kumpulkan semua huruf untuk kata:"),
+    (
+        Message::InstructionCaption,
+        "Kumpulkan semua huruf untuk kata",
+    ),
+    (Message::NotYet, "Belum! Cari huruf berikutnya dulu."),
+    (Message::GateOpen, "Gerbangnya terbuka!"),
+    (Message::ReplayFinished, "Tayangan ulang selesai"),
+    (Message::Level, "Level"),
+    (Message::Score, "Skor"),
+    (Message::Paused, "Jeda"),
+    (Message::Resume, "Lanjutkan"),
+    (Message::RestartLevel, "Ulangi Level"),
+    (Message::Settings, "Pengaturan"),
+    (Message::QuitToTitle, "Kembali ke Judul"),
+    (Message::WellDone, "Hebat!"),
+    (Message::PressSForSettings, "Tekan S untuk pengaturan"),
+    (Message::WhoIsPlaying, "Siapa yang bermain?"),
+    (Message::NewPlayer, "Pemain baru"),
+    (
+        Message::NameHint,
+        "Ketik nama, Kiri/Kanan untuk warna, Enter untuk mulai",
+    ),
+    (Message::LevelHint, "Kiri/Kanan untuk memilih level"),
+    (Message::WalkingSpeed, "Kecepatan jalan"),
+    (Message::Volume, "Volume"),
+    (Message::Voice, "Suara"),
+    (Message::Music, "Musik"),
+    (Message::Effects, "Efek"),
+    (Message::Captions, "Teks"),
+    (Message::TextSize, "Ukuran teks"),
+    (Message::Controls, "Kontrol"),
+    (Message::Language, "Bahasa"),
+    (Message::Back, "Kembali"),
+    (Message::On, "Nyala"),
+    (Message::Off, "Mati"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Message {
+    Instruction,
+    InstructionCaption,
+    NotYet,
+    GateOpen,
+    ReplayFinished,
+    SessionSaved,
+    SessionSavedAs,
+    SessionNotSaved,
+    NoSessionToReplay,
+    EventLogSaved,
+    EventLogNotSaved,
+    Level,
+    Score,
+    Paused,
+    Resume,
+    RestartLevel,
+    Settings,
+    QuitToTitle,
+    WellDone,
+    PressSForSettings,
+    WhoIsPlaying,
+    NewPlayer,
+    NameHint,
+    LevelHint,
+    WalkingSpeed,
+    Volume,
+    Voice,
+    Music,
+    Effects,
+    Captions,
+    TextSize,
+    Controls,
+    Language,
+    Back,
+    On,
+    Off,
+    KeyboardAndMouse,
+    Keyboard,
+    Mouse,
+}
+
+impl Message {
+    fn english(&self) -> &'static str {
+        match self {
+            Message::Instruction => "Collect all the letters for the word:",
+            Message::InstructionCaption => "Collect all the letters for the word",
+            Message::NotYet => "Not yet! Find the next letter first.",
+            Message::GateOpen => "The gate is open!",
+            Message::ReplayFinished => "Replay finished",
+            Message::SessionSaved => "Session saved",
+            Message::SessionSavedAs => "Session saved as",
+            Message::SessionNotSaved => "Could not save the session",
+            Message::NoSessionToReplay => "No saved session to replay",
+            Message::EventLogSaved => "Event log saved as",
+            Message::EventLogNotSaved => "Could not export the event log",
+            Message::Level => "Level",
+            Message::Score => "Score",
+            Message::Paused => "Paused",
+            Message::Resume => "Resume",
+            Message::RestartLevel => "Restart Level",
+            Message::Settings => "Settings",
+            Message::QuitToTitle => "Quit to Title",
+            Message::WellDone => "Well done!",
+            Message::PressSForSettings => "Press S for settings",
+            Message::WhoIsPlaying => "Who is playing?",
+            Message::NewPlayer => "New player",
+            Message::NameHint => "Type a name, Left/Right for a color, Enter to start",
+            Message::LevelHint => "Left/Right to pick a level",
+            Message::WalkingSpeed => "Walking speed",
+            Message::Volume => "Volume",
+            Message::Voice => "Voice",
+            Message::Music => "Music",
+            Message::Effects => "Effects",
+            Message::Captions => "Captions",
+            Message::TextSize => "Text size",
+            Message::Controls => "Controls",
+            Message::Language => "Language",
+            Message::Back => "Back",
+            Message::On => "On",
+            Message::Off => "Off",
+            Message::KeyboardAndMouse => "Keyboard + Mouse",
+            Message::Keyboard => "Keyboard",
+            Message::Mouse => "Mouse",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_translations_fall_back_to_english() {
+        assert_eq!(Language::Indonesian.text(Message::Paused), "Jeda");
+        assert_eq!(
+            Language::Indonesian.text(Message::SessionSaved),
+            "Session saved"
+        );
+        assert_eq!(Language::English.text(Message::Paused), "Paused");
+    }
+
+    #[test]
+    fn translations_are_listed_once() {
+        for language in LANGUAGES.iter() {
+            let table = language.table();
+            for (index, (message, _)) in table.iter().enumerate() {
+                assert!(
+                    table[index + 1..].iter().all(|(other, _)| other != message),
+                    "{:?} {:?}",
+                    language,
+                    message
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn voices_fall_back_to_english() {
+        assert_eq!(
+            Language::English.sounds("instructions"),
+            vec!["resources/sounds/en/instructions"]
+        );
+        assert_eq!(
+            Language::Indonesian.sounds("apple"),
+            vec!["resources/sounds/id/apple", "resources/sounds/en/apple"]
+        );
+    }
+
+    #[test]
+    fn languages_cycle_both_ways() {
+        assert_eq!(Language::English.cycle(1), Language::Indonesian);
+        assert_eq!(Language::English.cycle(-1), Language::Indonesian);
+        for language in LANGUAGES.iter() {
+            assert_eq!(language.cycle(1).cycle(-1), *language);
+            assert_eq!(language.cycle(LANGUAGES.len() as i32), *language);
+        }
+    }
+}
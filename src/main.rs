@@ -1,122 +1,123 @@
 #[macro_use]
 extern crate lazy_static;
 
+#[cfg(not(target_arch = "wasm32"))]
+extern crate dirs;
 extern crate futures;
 extern crate nalgebra;
 extern crate quicksilver;
+#[cfg(not(target_arch = "wasm32"))]
+extern crate rodio;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
 
-// #[macro_use]
-// extern crate stdweb;
+#[cfg(target_arch = "wasm32")]
+#[macro_use]
+extern crate stdweb;
 extern crate tiled;
 
+mod accessibility;
+mod assets;
+mod audio;
+mod camera;
+mod captions;
+mod clip;
+mod clock;
 mod collectible;
+mod config;
 mod constant;
+mod debug_overlay;
 mod direction;
+mod display;
+mod event_log;
+mod export;
+mod finished;
 mod game_layer;
 mod game_map;
 mod gate;
 mod grid;
+mod hud;
+mod instructions;
 mod level;
+mod level_spec;
+mod loading;
+mod locale;
+mod movement;
+mod music;
+mod notice;
+mod paused;
 mod player;
 mod player_state;
+mod playing;
 mod primitive;
+mod profile;
+mod profile_picker;
 mod puzzle;
+mod recording;
+mod scene;
+mod scheduler;
+mod settings;
+mod simulation;
+mod sound_effects;
+mod sound_format;
 mod splash;
+mod terrain;
+mod text;
+mod transition;
+mod word_bank;
 
-use level::Level;
-use player::Player;
+use assets::Assets;
 use quicksilver::{
     geom::Vector,
-    graphics::Color,
+    graphics::{Color, ResizeStrategy},
     lifecycle::{run, Settings, State, Window},
     Result,
 };
+use scene::{Context, SceneStack};
 use splash::Splash;
 
 struct RoboRex {
-    time: f64,
-    player: Player,
-    level: Level,
-    state: GameState,
-    splash: Splash,
-}
-
-enum GameState {
-    Splash,
-    Playing,
-}
-
-impl RoboRex {
-    fn start_level(&mut self, level: Level) {
-        self.level = level;
-        self.player.position = self.level.start_position.clone();
-    }
-
-    fn update_playing(&mut self, window: &mut Window) -> Result<()> {
-        self.time += window.update_rate();
-        self.level.update(window, &mut self.player)?;
-
-        if self.level.passing_the_gate(&self.player) {
-            let next_level = self.level.next_level();
-            match next_level {
-                Some(level) => self.start_level(level),
-                None => panic!("Finish level not implemented yet"),
-            }
-        }
-
-        Ok(())
-    }
-
-    fn update_splash(&mut self, window: &mut Window) -> Result<()> {
-        self.splash.update(window)?;
-        if (self.splash.is_clicked()) {
-            self.state = GameState::Playing;
-        }
-
-        Ok(())
-    }
-
-    fn draw_playing(&mut self, window: &mut Window) -> Result<()> {
-        window.clear(Color::BLACK)?;
-        self.level.draw(window, &mut self.player)?;
-        Ok(())
-    }
-
-    fn draw_splash(&mut self, window: &mut Window) -> Result<()> {
-        self.splash.draw(window)?;
-        Ok(())
-    }
+    context: Context,
+    scenes: SceneStack,
 }
 
 impl State for RoboRex {
     fn new() -> Result<RoboRex> {
-        let splash = Splash::new();
-        let level = Level::start();
-        let mut player = Player::new();
-        player.position = level.start_position.clone();
-        let roborex = RoboRex {
-            time: 0.,
-            player,
-            level,
-            splash,
-            state: GameState::Splash,
-        };
+        let context = Context::new(Assets::new());
+        let scenes = SceneStack::new(Box::new(Splash::new(&context)));
 
-        Ok(roborex)
+        Ok(RoboRex { context, scenes })
     }
 
     fn update(&mut self, window: &mut Window) -> Result<()> {
-        match self.state {
-            GameState::Splash => self.update_splash(window),
-            GameState::Playing => self.update_playing(window),
+        let context = &mut self.context;
+        context.display.update(window)?;
+        context.debug.update(window);
+        context.audio.update(window);
+        context.notice.update(window);
+        context.music.update(window.update_rate(), &context.audio)?;
+        if context.accessibility.update(window) {
+            context.text.set_accessibility(context.accessibility);
         }
+
+        context.export_event_log(window);
+        self.scenes.update(window, context)?;
+        context.save_config();
+        context.record_progress();
+        Ok(())
     }
 
     fn draw(&mut self, window: &mut Window) -> Result<()> {
-        match self.state {
-            GameState::Splash => self.draw_splash(window),
-            GameState::Playing => self.draw_playing(window),
-        }
+        window.clear(Color::BLACK)?;
+        self.scenes.draw(window, &mut self.context)?;
+        self.context
+            .audio
+            .captions()
+            .draw(window, &mut self.context.text)?;
+        self.context.notice.draw(window, &mut self.context.text)?;
+        self.context.display.draw_letterbox(window)
     }
 }
 
@@ -124,6 +125,14 @@ fn main() {
     run::<RoboRex>(
         "RoboRex",
         Vector::new(constant::WINDOW_WIDTH, constant::WINDOW_HEIGHT),
-        Settings::default(),
+        Settings {
+            resize: ResizeStrategy::Stretch,
+            update_rate: clock::STEP,
+            min_size: Some(Vector::new(
+                constant::WINDOW_WIDTH / 2,
+                constant::WINDOW_HEIGHT / 2,
+            )),
+            ..Settings::default()
+        },
     );
 }
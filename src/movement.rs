@@ -0,0 +1,258 @@
+use constant::WALKING_DURATION;
+use direction::Direction;
+use player_state::PlayerState;
+use primitive::Position;
+use simulation::{Event, Input};
+use terrain::Terrain;
+
+pub struct Movement {
+    pub position: Position,
+    pub state: PlayerState,
+    walking_speed: f64,
+}
+
+impl Movement {
+    pub fn new(position: Position) -> Self {
+        Movement {
+            position,
+            state: PlayerState::Standing(Direction::Right),
+            walking_speed: 1.,
+        }
+    }
+
+    pub fn walking_speed(&self) -> f64 {
+        self.walking_speed
+    }
+
+    pub fn set_walking_speed(&mut self, walking_speed: f64) {
+        self.walking_speed = walking_speed;
+    }
+
+    pub fn is_walking(&self) -> bool {
+        match self.state {
+            PlayerState::Walking { .. } => true,
+            PlayerState::Standing(_) => false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        input: &Input,
+        elapsed: f64,
+        terrain: &Terrain,
+        events: &mut Vec<Event>,
+    ) {
+        if let PlayerState::Walking {
+            direction,
+            timer,
+            grid_count,
+        } = self.state
+        {
+            if !terrain.can_walk_to(&self.next_position(direction)) {
+                events.push(Event::Bump);
+                self.stop();
+                return;
+            }
+            if timer <= 0. {
+                self.position = self.next_position(direction);
+                events.push(Event::Footstep);
+                if grid_count > 1 {
+                    self.state.grid_count(grid_count - 1);
+                    self.state.timer(WALKING_DURATION);
+                } else if input.directions().is_empty() {
+                    self.stop();
+                } else {
+                    for direction in input.directions() {
+                        self.walk(direction, terrain, events);
+                    }
+                }
+            } else {
+                self.state.timer(timer - elapsed * self.walking_speed);
+            }
+        }
+
+        for direction in input.directions() {
+            if !self.is_walking() {
+                self.walk(direction, terrain, events);
+            }
+        }
+
+        if let Some(ref target) = input.target {
+            if !self.is_walking() {
+                self.walk_to(target);
+            }
+        }
+    }
+
+    fn next_position(&self, direction: Direction) -> Position {
+        match direction {
+            Direction::Right => self.position.add(1, 0),
+            Direction::Left if self.position.x < 1 => self.position.clone(),
+            Direction::Left => self.position.add(-1, 0),
+            Direction::Up if self.position.y < 1 => self.position.clone(),
+            Direction::Up => self.position.add(0, -1),
+            Direction::Down => self.position.add(0, 1),
+        }
+    }
+
+    fn stop(&mut self) {
+        self.state = PlayerState::stop(&self.state);
+    }
+
+    fn walk(&mut self, direction: Direction, terrain: &Terrain, events: &mut Vec<Event>) {
+        let next_position = self.next_position(direction);
+        if terrain.can_walk_to(&next_position) && self.position != next_position {
+            self.state = PlayerState::Walking {
+                direction,
+                grid_count: 1,
+                timer: WALKING_DURATION,
+            };
+        } else {
+            events.push(Event::Bump);
+            self.stop();
+        }
+    }
+
+    fn walk_to(&mut self, next_position: &Position) {
+        let (direction_x, grid_count_x) = match next_position.x > self.position.x {
+            true => (Direction::Right, next_position.x - self.position.x),
+            false if next_position.x < self.position.x => {
+                (Direction::Left, self.position.x - next_position.x)
+            }
+            _ => (Direction::Right, 0),
+        };
+
+        let (direction_y, grid_count_y) = match next_position.y > self.position.y {
+            true => (Direction::Down, next_position.y - self.position.y),
+            false if next_position.y < self.position.y => {
+                (Direction::Up, self.position.y - next_position.y)
+            }
+            _ => (Direction::Right, 0),
+        };
+
+        self.state = match (grid_count_x, grid_count_y) {
+            (0, 0) => PlayerState::stop(&self.state),
+            _ if grid_count_x >= grid_count_y => PlayerState::Walking {
+                direction: direction_x,
+                grid_count: grid_count_x,
+                timer: WALKING_DURATION,
+            },
+            _ => PlayerState::Walking {
+                direction: direction_y,
+                grid_count: grid_count_y,
+                timer: WALKING_DURATION,
+            },
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: u32 = 197;
+    const WALL: u32 = 1;
+
+    fn terrain() -> Terrain {
+        Terrain::new(
+            &[vec![
+                vec![PATH, PATH, PATH, PATH, WALL],
+                vec![PATH, WALL, WALL, PATH, WALL],
+                vec![PATH, PATH, PATH, PATH, WALL],
+            ]],
+            Position::new(9, 9),
+        )
+    }
+
+    fn run(movement: &mut Movement, input: &Input, ticks: u32) -> Vec<Event> {
+        let terrain = terrain();
+        let mut events = Vec::new();
+        for _ in 0..ticks {
+            movement.update(input, WALKING_DURATION / 2., &terrain, &mut events);
+        }
+        events
+    }
+
+    fn holding(direction: Direction) -> Input {
+        Input {
+            right: direction == Direction::Right,
+            left: direction == Direction::Left,
+            up: direction == Direction::Up,
+            down: direction == Direction::Down,
+            target: None,
+        }
+    }
+
+    #[test]
+    fn holding_a_direction_walks_one_cell_at_a_time() {
+        let mut movement = Movement::new(Position::new(0, 0));
+        let events = run(&mut movement, &holding(Direction::Right), 4);
+        assert_eq!(movement.position, Position::new(1, 0));
+        assert_eq!(events, vec![Event::Footstep]);
+
+        run(&mut movement, &holding(Direction::Right), 3);
+        assert_eq!(movement.position, Position::new(2, 0));
+    }
+
+    #[test]
+    fn releasing_the_key_stops_after_the_current_cell() {
+        let mut movement = Movement::new(Position::new(0, 0));
+        run(&mut movement, &holding(Direction::Right), 1);
+        run(&mut movement, &Input::default(), 10);
+        assert_eq!(movement.position, Position::new(1, 0));
+        assert_eq!(movement.state, PlayerState::Standing(Direction::Right));
+    }
+
+    #[test]
+    fn walls_and_map_edges_bump() {
+        let mut movement = Movement::new(Position::new(0, 1));
+        let events = run(&mut movement, &holding(Direction::Right), 1);
+        assert_eq!(events, vec![Event::Bump]);
+        assert_eq!(movement.position, Position::new(0, 1));
+
+        let mut movement = Movement::new(Position::new(0, 0));
+        let events = run(&mut movement, &holding(Direction::Left), 1);
+        assert_eq!(events, vec![Event::Bump]);
+        assert_eq!(movement.state, PlayerState::Standing(Direction::Right));
+    }
+
+    #[test]
+    fn walking_to_a_target_moves_along_the_longer_axis_first() {
+        let mut movement = Movement::new(Position::new(0, 0));
+        let input = Input {
+            target: Some(Position::new(3, 0)),
+            ..Input::default()
+        };
+        run(&mut movement, &input, 1);
+        let events = run(&mut movement, &Input::default(), 9);
+        assert_eq!(movement.position, Position::new(3, 0));
+        assert_eq!(events, vec![Event::Footstep; 3]);
+        assert!(!movement.is_walking());
+    }
+
+    #[test]
+    fn walking_to_a_target_stops_at_walls() {
+        let mut movement = Movement::new(Position::new(1, 0));
+        let input = Input {
+            target: Some(Position::new(1, 2)),
+            ..Input::default()
+        };
+        run(&mut movement, &input, 1);
+        let events = run(&mut movement, &Input::default(), 1);
+        assert_eq!(events, vec![Event::Bump]);
+        assert_eq!(movement.position, Position::new(1, 0));
+    }
+
+    #[test]
+    fn walking_speed_scales_the_step_duration() {
+        let mut movement = Movement::new(Position::new(0, 0));
+        movement.set_walking_speed(2.);
+        run(&mut movement, &holding(Direction::Down), 3);
+        assert_eq!(movement.position, Position::new(0, 1));
+
+        let mut movement = Movement::new(Position::new(0, 0));
+        movement.set_walking_speed(0.5);
+        run(&mut movement, &holding(Direction::Down), 4);
+        assert_eq!(movement.position, Position::new(0, 0));
+    }
+}
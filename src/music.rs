@@ -0,0 +1,195 @@
+use assets::{Assets, Handle};
+use audio::{Audio, Channel};
+use clip::Output;
+use quicksilver::Result;
+#[cfg(not(target_arch = "wasm32"))]
+use rodio;
+#[cfg(target_arch = "wasm32")]
+use stdweb::{self, web::TypedArray};
+
+pub const DEFAULT_TRACK: &str = "resources/music/default";
+const CROSSFADE_DURATION: f64 = 1500.;
+
+struct Track {
+    path: String,
+    data: Handle<Vec<u8>>,
+    player: Option<Player>,
+    fade: f64,
+}
+
+impl Track {
+    fn new(path: &str, assets: &Assets) -> Self {
+        Track {
+            path: path.to_string(),
+            data: assets.track(path),
+            player: None,
+            fade: 0.,
+        }
+    }
+
+    fn update(&mut self, step: f64, volume: f32, output: &Output) -> Result<()> {
+        if self.player.is_none() {
+            let player = &mut self.player;
+            self.data.execute(|data| {
+                *player = Some(Player::new(data, output)?);
+                Ok(())
+            })?;
+        }
+
+        if let Some(ref mut player) = self.player {
+            self.fade = (self.fade + step).clamp(0., 1.);
+            player.set_volume(volume * self.fade as f32);
+        }
+
+        Ok(())
+    }
+
+    fn is_silent(&self) -> bool {
+        self.player.is_none() || self.fade <= 0.
+    }
+}
+
+pub struct Music {
+    assets: Assets,
+    current: Option<Track>,
+    fading: Vec<Track>,
+    started: bool,
+    paused: bool,
+}
+
+impl Music {
+    pub fn new(assets: &Assets) -> Self {
+        Music {
+            assets: assets.clone(),
+            current: None,
+            fading: Vec::new(),
+            started: false,
+            paused: false,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.started = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+        for track in self.current.iter_mut().chain(self.fading.iter_mut()) {
+            if let Some(ref mut player) = track.player {
+                player.pause();
+            }
+        }
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+        for track in self.current.iter_mut().chain(self.fading.iter_mut()) {
+            if let Some(ref mut player) = track.player {
+                player.resume();
+            }
+        }
+    }
+
+    pub fn play(&mut self, path: &str) {
+        if let Some(ref current) = self.current {
+            if current.path == path {
+                return;
+            }
+        }
+
+        let track = Track::new(path, &self.assets);
+        if let Some(previous) = self.current.replace(track) {
+            self.fading.push(previous);
+        }
+    }
+
+    pub fn update(&mut self, elapsed: f64, audio: &Audio) -> Result<()> {
+        if !self.started || self.paused {
+            return Ok(());
+        }
+
+        let step = elapsed / CROSSFADE_DURATION;
+        let volume = audio.volume(Channel::Music);
+        let output = audio.output();
+        if let Some(ref mut current) = self.current {
+            current.update(step, volume, output)?;
+        }
+
+        for track in self.fading.iter_mut() {
+            track.update(-step, volume, output)?;
+        }
+        self.fading.retain(|track| !track.is_silent());
+
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct Player {
+    sink: rodio::Sink,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Player {
+    fn new(data: &[u8], output: &Output) -> Result<Self> {
+        use rodio::{Decoder, Source};
+        use std::io::Cursor;
+
+        let mut sink = output.sink()?;
+        sink.set_volume(0.);
+        sink.append(Decoder::new(Cursor::new(data.to_vec()))?.repeat_infinite());
+        Ok(Player { sink })
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        self.sink.set_volume(volume);
+    }
+
+    fn pause(&mut self) {
+        self.sink.pause();
+    }
+
+    fn resume(&mut self) {
+        self.sink.play();
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+struct Player {
+    element: stdweb::Value,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Player {
+    fn new(data: &[u8], _output: &Output) -> Result<Self> {
+        let bytes = TypedArray::<u8>::from(data);
+        let element = js! {
+            var audio = new Audio(URL.createObjectURL(new Blob([@{bytes}])));
+            audio.loop = true;
+            audio.volume = 0;
+            audio.play();
+            return audio;
+        };
+        Ok(Player { element })
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        js! { @(no_return) @{&self.element}.volume = @{volume}; }
+    }
+
+    fn pause(&mut self) {
+        js! { @(no_return) @{&self.element}.pause(); }
+    }
+
+    fn resume(&mut self) {
+        js! { @(no_return) @{&self.element}.play(); }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for Player {
+    fn drop(&mut self) {
+        self.pause();
+        js! { @(no_return) URL.revokeObjectURL(@{&self.element}.src); }
+    }
+}
@@ -0,0 +1,67 @@
+use constant::{NOTICE_Z, WINDOW_WIDTH};
+use quicksilver::{
+    geom::{Rectangle, Shape, Transform, Vector},
+    graphics::{Background::Col, Background::Img, Color},
+    lifecycle::Window,
+    Result,
+};
+use text::{Text, TextStyle};
+
+const TOP: f32 = 136.;
+const PADDING: f32 = 8.;
+const DURATION: f64 = 4000.;
+
+lazy_static! {
+    static ref NOTICE: TextStyle = TextStyle::new(18.0, Color::WHITE);
+    static ref BACKGROUND: Color = Color::BLACK.with_alpha(0.85);
+}
+
+pub struct Notice {
+    message: Option<String>,
+    timer: f64,
+}
+
+impl Notice {
+    pub fn new() -> Self {
+        Notice {
+            message: None,
+            timer: 0.,
+        }
+    }
+
+    pub fn show(&mut self, message: &str) {
+        self.message = Some(message.to_string());
+        self.timer = DURATION;
+    }
+
+    pub fn update(&mut self, window: &Window) {
+        self.timer -= window.update_rate();
+        if self.timer <= 0. {
+            self.message = None;
+        }
+    }
+
+    pub fn draw(&mut self, window: &mut Window, text: &mut Text) -> Result<()> {
+        let message = match self.message {
+            Some(ref message) => message,
+            None => return Ok(()),
+        };
+
+        if let Some(message_text) = text.render_uncached(message, &NOTICE)? {
+            let area = message_text.area().with_center((
+                WINDOW_WIDTH as f32 / 2.,
+                TOP + message_text.area().height() / 2.,
+            ));
+            let padding = Vector::new(PADDING, PADDING);
+            window.draw_ex(
+                &Rectangle::new(area.pos - padding, area.size + padding * 2),
+                Col(*BACKGROUND),
+                Transform::IDENTITY,
+                NOTICE_Z,
+            );
+            window.draw_ex(&area, Img(&message_text), Transform::IDENTITY, NOTICE_Z + 1);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use constant::{HUD_Z, WINDOW_HEIGHT, WINDOW_WIDTH};
+use display::Display;
+use locale::Message;
+use quicksilver::{
+    geom::{Rectangle, Shape, Transform, Vector},
+    graphics::{Background::Col, Background::Img, Color},
+    input::{ButtonState, Key, MouseButton},
+    lifecycle::Window,
+    Result,
+};
+use scene::{Context, Scene, SceneChange, SceneResult};
+use settings::Settings;
+use text::TextStyle;
+
+const OPTION_WIDTH: f32 = 320.;
+const OPTION_HEIGHT: f32 = 48.;
+const OPTION_SPACING: f32 = 16.;
+
+lazy_static! {
+    static ref TITLE: TextStyle = TextStyle::new(42.0, Color::WHITE);
+    static ref OPTION: TextStyle = TextStyle::new(24.0, Color::WHITE);
+    static ref SHADE: Color = Color::BLACK.with_alpha(0.6);
+    static ref BUTTON: Color = Color::BLACK.with_alpha(0.8);
+    static ref SELECTED: Color = Color::WHITE.with_alpha(0.3);
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum MenuOption {
+    Resume,
+    Restart,
+    Settings,
+    Quit,
+}
+
+const OPTIONS: [MenuOption; 4] = [
+    MenuOption::Resume,
+    MenuOption::Restart,
+    MenuOption::Settings,
+    MenuOption::Quit,
+];
+
+impl MenuOption {
+    fn label(&self) -> Message {
+        match self {
+            MenuOption::Resume => Message::Resume,
+            MenuOption::Restart => Message::RestartLevel,
+            MenuOption::Settings => Message::Settings,
+            MenuOption::Quit => Message::QuitToTitle,
+        }
+    }
+
+    fn result(&self) -> Option<SceneResult> {
+        match self {
+            MenuOption::Resume => Some(SceneResult::Resumed),
+            MenuOption::Restart => Some(SceneResult::Restart),
+            MenuOption::Settings => None,
+            MenuOption::Quit => Some(SceneResult::QuitToTitle),
+        }
+    }
+
+    fn area(index: usize) -> Rectangle {
+        let top = WINDOW_HEIGHT as f32 / 2. - OPTION_HEIGHT;
+        Rectangle::new(
+            (
+                (WINDOW_WIDTH as f32 - OPTION_WIDTH) / 2.,
+                top + index as f32 * (OPTION_HEIGHT + OPTION_SPACING),
+            ),
+            (OPTION_WIDTH, OPTION_HEIGHT),
+        )
+    }
+}
+
+pub struct Paused {
+    selected: usize,
+}
+
+impl Paused {
+    pub fn new(context: &mut Context) -> Self {
+        context.music.pause();
+        context.audio.set_paused(true);
+        Paused { selected: 0 }
+    }
+
+    fn choose(&mut self, option: MenuOption, context: &mut Context) -> SceneChange {
+        match option.result() {
+            Some(result) => {
+                context.music.resume();
+                match result {
+                    SceneResult::Resumed => {}
+                    _ => context.audio.stop(),
+                }
+                context.audio.set_paused(false);
+                SceneChange::Pop(result)
+            }
+            None => SceneChange::Push(Box::new(Settings::new())),
+        }
+    }
+}
+
+impl Scene for Paused {
+    fn update(&mut self, window: &mut Window, context: &mut Context) -> Result<SceneChange> {
+        let keyboard = window.keyboard();
+        if keyboard[Key::P] == ButtonState::Pressed || keyboard[Key::Escape] == ButtonState::Pressed
+        {
+            return Ok(self.choose(MenuOption::Resume, context));
+        }
+
+        if keyboard[Key::Up] == ButtonState::Pressed {
+            self.selected = (self.selected + OPTIONS.len() - 1) % OPTIONS.len();
+        }
+
+        if keyboard[Key::Down] == ButtonState::Pressed {
+            self.selected = (self.selected + 1) % OPTIONS.len();
+        }
+
+        if keyboard[Key::Return] == ButtonState::Pressed {
+            return Ok(self.choose(OPTIONS[self.selected], context));
+        }
+
+        let pointer = Display::pointer(window);
+        let hovered = (0..OPTIONS.len()).find(|index| MenuOption::area(*index).contains(pointer));
+        if let Some(index) = hovered {
+            self.selected = index;
+            if window.mouse()[MouseButton::Left] == ButtonState::Released {
+                return Ok(self.choose(OPTIONS[index], context));
+            }
+        }
+
+        Ok(SceneChange::Stay)
+    }
+
+    fn draw(&mut self, window: &mut Window, context: &mut Context) -> Result<()> {
+        window.draw_ex(
+            &Rectangle::new_sized((WINDOW_WIDTH, WINDOW_HEIGHT)),
+            Col(*SHADE),
+            Transform::IDENTITY,
+            HUD_Z + 5,
+        );
+        let language = context.config.language;
+        if let Some(title_text) = context
+            .text
+            .render(language.text(Message::Paused), &TITLE)?
+        {
+            let top = MenuOption::area(0).y() - OPTION_HEIGHT;
+            window.draw_ex(
+                &title_text
+                    .area()
+                    .with_center(Vector::new(WINDOW_WIDTH as f32 / 2., top)),
+                Img(&title_text),
+                Transform::IDENTITY,
+                HUD_Z + 6,
+            );
+        }
+
+        for (index, option) in OPTIONS.iter().enumerate() {
+            let area = MenuOption::area(index);
+            let background = if index == self.selected {
+                *SELECTED
+            } else {
+                *BUTTON
+            };
+            window.draw_ex(&area, Col(background), Transform::IDENTITY, HUD_Z + 6);
+            if let Some(label) = context
+                .text
+                .render(language.text(option.label()), &OPTION)?
+            {
+                window.draw_ex(
+                    &label.area().with_center(area.center()),
+                    Img(&label),
+                    Transform::IDENTITY,
+                    HUD_Z + 7,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -1,28 +1,16 @@
 use direction::Direction;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum PlayerState {
     Standing(Direction),
     Walking {
         direction: Direction,
         grid_count: u32,
         timer: f64,
-        sprites_idx: usize,
-        tick: f64,
     },
 }
 
 impl PlayerState {
-    pub fn sprites_idx(&mut self, new_sprites_idx: usize) {
-        if let PlayerState::Walking {
-            ref mut sprites_idx,
-            ..
-        } = self
-        {
-            *sprites_idx = new_sprites_idx;
-        }
-    }
-
     pub fn timer(&mut self, new_timer: f64) {
         if let PlayerState::Walking { ref mut timer, .. } = self {
             *timer = new_timer;
@@ -37,12 +25,6 @@ impl PlayerState {
             *grid_count = new_grid_count;
         }
     }
-
-    pub fn tick(&mut self, new_tick: f64) {
-        if let PlayerState::Walking { ref mut tick, .. } = self {
-            *tick = new_tick;
-        }
-    }
 }
 
 impl PlayerState {
@@ -0,0 +1,204 @@
+use event_log::Activity;
+use finished::Finished;
+use hud::{Button, Hud};
+use level::Level;
+use loading::Loading;
+use locale::Message;
+use paused::Paused;
+use player::Player;
+use quicksilver::{
+    input::{ButtonState, Key},
+    lifecycle::Window,
+    Result,
+};
+use recording::Session;
+use scene::{Context, Scene, SceneChange, SceneResult};
+use splash::Splash;
+use transition::{Effect, Transition};
+
+pub struct Playing {
+    time: f64,
+    level: Level,
+    player: Player,
+    hud: Hud,
+    score: u32,
+    paused: bool,
+}
+
+impl Playing {
+    pub fn new(level: Level, score: u32, context: &mut Context) -> Self {
+        level.log(&mut context.event_log, Activity::LevelStarted);
+        let mut playing = Playing {
+            time: 0.,
+            level,
+            player: Player::new(&context.assets),
+            hud: Hud::new(),
+            score,
+            paused: false,
+        };
+        playing.configure(context);
+        playing
+    }
+
+    fn configure(&mut self, context: &Context) {
+        self.player.configure(&context.config);
+        self.level.configure(&context.config, &context.assets);
+        self.hud.set_language(context.config.language);
+    }
+
+    fn update_hud(&mut self, context: &Context) {
+        self.hud.set_status(
+            self.level.index(),
+            self.score + self.level.score(),
+            context.audio.is_muted(),
+            self.paused,
+        );
+    }
+
+    fn save_session(&mut self, context: &mut Context) {
+        let language = context.config.language;
+        let session = self.level.session();
+        let saved = match session {
+            Some(ref session) => session.save(),
+            None => false,
+        };
+        let message = match session.and_then(|session| session.export()) {
+            Some(location) => format!("{} {}", language.text(Message::SessionSavedAs), location),
+            None if saved => language.text(Message::SessionSaved).to_string(),
+            None => language.text(Message::SessionNotSaved).to_string(),
+        };
+        context.notice.show(&message);
+    }
+
+    fn replay_session(&mut self, context: &mut Context) -> Option<SceneChange> {
+        let session = match Session::load() {
+            Some(session) => session,
+            None => {
+                let language = context.config.language;
+                context
+                    .notice
+                    .show(language.text(Message::NoSessionToReplay));
+                return None;
+            }
+        };
+        let level = Level::new(
+            session.recording.level,
+            &session.recording.word,
+            context.config.language,
+            &context.assets,
+        )?
+        .replaying(&session.recording);
+        let loading = Loading::new(level, self.score, context);
+        Some(SceneChange::Transition(
+            Transition::new(Effect::Fade),
+            Box::new(SceneChange::Replace(Box::new(loading))),
+        ))
+    }
+
+    fn next_level(&mut self, context: &mut Context) -> SceneChange {
+        let score = self.score + self.level.score();
+        let index = self.level.index() + 1;
+        let level = context
+            .profiles
+            .word_for(index)
+            .and_then(|word| Level::new(index, &word, context.config.language, &context.assets));
+        match level {
+            Some(level) => {
+                let transition = Transition::new(Effect::Iris).with_title(level.title());
+                let loading = Loading::new(level, score, context);
+                SceneChange::Transition(
+                    transition,
+                    Box::new(SceneChange::Replace(Box::new(loading))),
+                )
+            }
+            None => SceneChange::Transition(
+                Transition::new(Effect::Fade),
+                Box::new(SceneChange::Replace(Box::new(Finished::new()))),
+            ),
+        }
+    }
+}
+
+impl Scene for Playing {
+    fn update(&mut self, window: &mut Window, context: &mut Context) -> Result<SceneChange> {
+        self.time += window.update_rate();
+        let button = match self.hud.update(window) {
+            None if window.keyboard()[Key::Escape] == ButtonState::Pressed => Some(Button::Pause),
+            button => button,
+        };
+        match button {
+            Some(Button::Mute) => context.audio.toggle_mute(),
+            Some(Button::Replay) => {
+                self.level
+                    .log(&mut context.event_log, Activity::ReplayPressed);
+                self.level.replay_word(&mut context.audio)?
+            }
+            Some(Button::Pause) => self.paused = true,
+            None => {}
+        }
+
+        self.update_hud(context);
+        if self.paused {
+            return Ok(SceneChange::Push(Box::new(Paused::new(context))));
+        }
+
+        if window.keyboard()[Key::F1] == ButtonState::Pressed {
+            self.save_session(context);
+        }
+
+        if window.keyboard()[Key::F2] == ButtonState::Pressed {
+            if let Some(change) = self.replay_session(context) {
+                return Ok(change);
+            }
+        }
+
+        self.level.update(
+            window,
+            &mut self.player,
+            &mut context.audio,
+            &mut context.event_log,
+            self.hud.click(),
+        )?;
+
+        if self.level.passing_the_gate() {
+            self.level
+                .log(&mut context.event_log, Activity::LevelCompleted);
+            context.record_progress();
+            return Ok(self.next_level(context));
+        }
+
+        Ok(SceneChange::Stay)
+    }
+
+    fn resume(&mut self, result: SceneResult, context: &mut Context) -> Result<SceneChange> {
+        self.paused = false;
+        self.level.resume();
+        self.configure(context);
+        self.update_hud(context);
+        match result {
+            SceneResult::Resumed => Ok(SceneChange::Stay),
+            SceneResult::Restart => {
+                self.level.restart()?;
+                self.level
+                    .log(&mut context.event_log, Activity::LevelStarted);
+                Ok(SceneChange::Stay)
+            }
+            SceneResult::QuitToTitle => Ok(SceneChange::Transition(
+                Transition::new(Effect::Fade),
+                Box::new(SceneChange::Replace(Box::new(Splash::new(context)))),
+            )),
+        }
+    }
+
+    fn draw(&mut self, window: &mut Window, context: &mut Context) -> Result<()> {
+        self.level.draw(
+            window,
+            &mut self.player,
+            &context.display,
+            &mut context.debug,
+            &mut context.text,
+        )?;
+        self.hud.draw(window, &mut context.text)?;
+        Ok(())
+    }
+}
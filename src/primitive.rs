@@ -10,7 +10,7 @@ impl Dimension {
     }
 }
 
-#[derive(PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub x: u32,
     pub y: u32,
@@ -0,0 +1,293 @@
+use config::Config;
+use event_log::{Activity, Entry};
+use level_spec::LevelSpec;
+use quicksilver::saving::{load, save};
+use scheduler::Scheduler;
+use word_bank;
+
+const APP_NAME: &str = "roborex";
+const PROFILES_PROFILE: &str = "profiles";
+pub const MAX_PROFILES: usize = 6;
+pub const MAX_NAME_LENGTH: usize = 12;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub levels_completed: u32,
+    pub letters_collected: u32,
+    pub wrong_letters: u32,
+    pub replays: u32,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub avatar: usize,
+    pub config: Config,
+    pub unlocked: u32,
+    pub stats: Stats,
+    #[serde(default)]
+    pub schedule: Scheduler,
+    #[serde(skip)]
+    attempt: Option<(String, u32)>,
+}
+
+impl Profile {
+    pub fn new(name: &str, avatar: usize, config: Config) -> Self {
+        Profile {
+            name: name.to_string(),
+            avatar,
+            config,
+            unlocked: 0,
+            stats: Stats::default(),
+            schedule: Scheduler::new(),
+            attempt: None,
+        }
+    }
+
+    pub fn is_unlocked(&self, level: u32) -> bool {
+        level <= self.unlocked
+    }
+
+    pub fn start_level(&self) -> u32 {
+        match LevelSpec::get(self.unlocked) {
+            Some(_) => self.unlocked,
+            None => 0,
+        }
+    }
+
+    pub fn next_word(&self, level: u32) -> Option<&'static str> {
+        let spec = LevelSpec::get(level)?;
+        let words: Vec<&'static str> = word_bank::unlocked(self.unlocked)
+            .into_iter()
+            .filter(|word| spec.fits(word))
+            .collect();
+        self.schedule.choose(&words)
+    }
+
+    pub fn record(&mut self, entry: &Entry) {
+        let mistakes = match self.attempt {
+            Some((ref word, mistakes)) if *word == entry.word => mistakes,
+            _ => 0,
+        };
+        let mistakes = match entry.activity {
+            Activity::LetterCollected { .. } => {
+                self.stats.letters_collected += 1;
+                mistakes
+            }
+            Activity::WrongLetter { .. } => {
+                self.stats.wrong_letters += 1;
+                mistakes + 1
+            }
+            Activity::ReplayPressed => {
+                self.stats.replays += 1;
+                mistakes + 1
+            }
+            Activity::LevelCompleted => {
+                self.stats.levels_completed += 1;
+                self.unlocked = self.unlocked.max(entry.level + 1);
+                self.schedule.review(&entry.word, mistakes);
+                0
+            }
+            Activity::LevelStarted | Activity::GateOpened => mistakes,
+        };
+        self.attempt = Some((entry.word.clone(), mistakes));
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Profiles {
+    profiles: Vec<Profile>,
+    #[serde(skip)]
+    current: Option<usize>,
+}
+
+impl Profiles {
+    pub fn new() -> Self {
+        Profiles {
+            profiles: Vec::new(),
+            current: None,
+        }
+    }
+
+    pub fn load() -> Self {
+        load(APP_NAME, PROFILES_PROFILE).unwrap_or_else(|_| Profiles::new())
+    }
+
+    pub fn save(&self) {
+        let _ = save(APP_NAME, PROFILES_PROFILE, self);
+    }
+
+    pub fn list(&self) -> &[Profile] {
+        &self.profiles
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.profiles.len() >= MAX_PROFILES
+    }
+
+    pub fn add(&mut self, profile: Profile) -> Option<usize> {
+        if self.is_full() {
+            return None;
+        }
+
+        self.profiles.push(profile);
+        Some(self.profiles.len() - 1)
+    }
+
+    pub fn select(&mut self, index: usize) -> Option<&Profile> {
+        self.current = if index < self.profiles.len() {
+            Some(index)
+        } else {
+            None
+        };
+        self.current()
+    }
+
+    pub fn current(&self) -> Option<&Profile> {
+        self.current.and_then(|index| self.profiles.get(index))
+    }
+
+    pub fn word_for(&self, level: u32) -> Option<String> {
+        self.current()
+            .and_then(|profile| profile.next_word(level))
+            .map(str::to_string)
+            .or_else(|| LevelSpec::get(level).map(|spec| spec.word))
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut Profile> {
+        match self.current {
+            Some(index) => self.profiles.get_mut(index),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: u32, activity: Activity) -> Entry {
+        played(level, "APPLE", activity)
+    }
+
+    fn played(level: u32, word: &str, activity: Activity) -> Entry {
+        Entry {
+            timestamp: 0,
+            profile: "Ada".to_string(),
+            level,
+            word: word.to_string(),
+            activity,
+        }
+    }
+
+    #[test]
+    fn completing_a_level_unlocks_the_next_one() {
+        let mut profile = Profile::new("Ada", 0, Config::new());
+        assert!(profile.is_unlocked(0));
+        assert!(!profile.is_unlocked(1));
+
+        profile.record(&entry(0, Activity::LevelCompleted));
+        assert!(profile.is_unlocked(1));
+        assert_eq!(profile.start_level(), 1);
+
+        profile.record(&entry(0, Activity::LevelCompleted));
+        assert_eq!(profile.unlocked, 1);
+        assert_eq!(profile.stats.levels_completed, 2);
+    }
+
+    #[test]
+    fn finishing_every_level_starts_over_from_the_first() {
+        let mut profile = Profile::new("Ada", 0, Config::new());
+        profile.record(&entry(0, Activity::LevelCompleted));
+        profile.record(&entry(1, Activity::LevelCompleted));
+        assert!(LevelSpec::get(profile.unlocked).is_none());
+        assert_eq!(profile.start_level(), 0);
+    }
+
+    #[test]
+    fn stats_count_letters_and_replays() {
+        let mut profile = Profile::new("Ada", 0, Config::new());
+        profile.record(&entry(0, Activity::LevelStarted));
+        profile.record(&entry(
+            1,
+            Activity::WrongLetter {
+                letter: 'P',
+                slot: 0,
+            },
+        ));
+        profile.record(&entry(
+            1,
+            Activity::LetterCollected {
+                letter: 'A',
+                slot: 0,
+            },
+        ));
+        profile.record(&entry(0, Activity::ReplayPressed));
+        assert_eq!(
+            profile.stats,
+            Stats {
+                levels_completed: 0,
+                letters_collected: 1,
+                wrong_letters: 1,
+                replays: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn words_are_scheduled_from_the_unlocked_bank() {
+        let mut profile = Profile::new("Ada", 0, Config::new());
+        assert_eq!(profile.next_word(0), Some("APPLE"));
+        assert_eq!(profile.next_word(1), Some("APPLE"));
+
+        assert!(!word_bank::unlocked(profile.unlocked).contains(&"JONATHAN"));
+
+        profile.record(&played(0, "APPLE", Activity::LevelCompleted));
+        assert!(word_bank::unlocked(profile.unlocked).contains(&"JONATHAN"));
+        assert_eq!(profile.next_word(0), Some("APPLE"));
+        assert_eq!(profile.next_word(1), Some("JONATHAN"));
+        assert_eq!(profile.next_word(9), None);
+    }
+
+    #[test]
+    fn mistakes_and_hints_bring_a_word_back_sooner() {
+        let mut profile = Profile::new("Ada", 0, Config::new());
+        profile.record(&played(0, "APPLE", Activity::LevelCompleted));
+        profile.record(&played(1, "JONATHAN", Activity::LevelStarted));
+        profile.record(&played(1, "JONATHAN", Activity::ReplayPressed));
+        profile.record(&played(1, "JONATHAN", Activity::LevelStarted));
+        profile.record(&played(1, "JONATHAN", Activity::LevelCompleted));
+        assert_eq!(
+            profile.schedule.choose(&["APPLE", "JONATHAN"]),
+            Some("JONATHAN")
+        );
+
+        let mut profile = Profile::new("Ada", 0, Config::new());
+        profile.record(&played(0, "APPLE", Activity::LevelCompleted));
+        profile.record(&played(1, "JONATHAN", Activity::LevelCompleted));
+        assert_eq!(
+            profile.schedule.choose(&["APPLE", "JONATHAN"]),
+            Some("APPLE")
+        );
+    }
+
+    #[test]
+    fn profiles_are_capped_and_selected_by_index() {
+        let mut profiles = Profiles::new();
+        for index in 0..MAX_PROFILES {
+            assert_eq!(
+                profiles.add(Profile::new("Ada", 0, Config::new())),
+                Some(index)
+            );
+        }
+        assert!(profiles.is_full());
+        assert!(profiles
+            .add(Profile::new("Grace", 0, Config::new()))
+            .is_none());
+
+        assert!(profiles.current().is_none());
+        assert!(profiles.select(2).is_some());
+        assert!(profiles.select(MAX_PROFILES).is_none());
+        assert!(profiles.current().is_none());
+    }
+}
@@ -0,0 +1,408 @@
+use constant::{HUD_Z, WINDOW_HEIGHT, WINDOW_WIDTH};
+use display::Display;
+use level::Level;
+use level_spec::LevelSpec;
+use loading::Loading;
+use locale::Message;
+use profile::{Profile, MAX_NAME_LENGTH};
+use quicksilver::{
+    geom::{Circle, Rectangle, Shape, Transform, Vector},
+    graphics::{Background::Col, Background::Img, Color},
+    input::{ButtonState, Key, MouseButton},
+    lifecycle::Window,
+    Result,
+};
+use scene::{Context, Scene, SceneChange};
+use text::TextStyle;
+use transition::{Effect, Transition};
+
+const ROW_WIDTH: f32 = 480.;
+const ROW_HEIGHT: f32 = 56.;
+const ROW_SPACING: f32 = 8.;
+const TOP: f32 = 110.;
+const PADDING: f32 = 16.;
+const AVATAR_RADIUS: f32 = 20.;
+
+const AVATARS: [Color; 6] = [
+    Color::RED,
+    Color::ORANGE,
+    Color::YELLOW,
+    Color::GREEN,
+    Color::CYAN,
+    Color::MAGENTA,
+];
+
+const LETTERS: [Key; 26] = [
+    Key::A,
+    Key::B,
+    Key::C,
+    Key::D,
+    Key::E,
+    Key::F,
+    Key::G,
+    Key::H,
+    Key::I,
+    Key::J,
+    Key::K,
+    Key::L,
+    Key::M,
+    Key::N,
+    Key::O,
+    Key::P,
+    Key::Q,
+    Key::R,
+    Key::S,
+    Key::T,
+    Key::U,
+    Key::V,
+    Key::W,
+    Key::X,
+    Key::Y,
+    Key::Z,
+];
+
+lazy_static! {
+    static ref TITLE: TextStyle = TextStyle::new(42.0, Color::WHITE);
+    static ref ROW: TextStyle = TextStyle::new(24.0, Color::WHITE);
+    static ref INITIAL: TextStyle = TextStyle::new(24.0, Color::BLACK);
+    static ref HINT: TextStyle = TextStyle::new(18.0, Color::WHITE);
+    static ref BUTTON: Color = Color::BLACK.with_alpha(0.8);
+    static ref SELECTED: Color = Color::WHITE.with_alpha(0.3);
+}
+
+struct NewProfile {
+    name: String,
+    avatar: usize,
+}
+
+pub struct ProfilePicker {
+    selected: usize,
+    levels: Vec<u32>,
+    new_profile: Option<NewProfile>,
+    chosen: bool,
+}
+
+impl ProfilePicker {
+    pub fn new(context: &Context) -> Self {
+        ProfilePicker {
+            selected: 0,
+            levels: context
+                .profiles
+                .list()
+                .iter()
+                .map(Profile::start_level)
+                .collect(),
+            new_profile: None,
+            chosen: false,
+        }
+    }
+
+    fn rows(&self, context: &Context) -> usize {
+        let profiles = context.profiles.list().len();
+        if context.profiles.is_full() {
+            profiles
+        } else {
+            profiles + 1
+        }
+    }
+
+    fn area(index: usize) -> Rectangle {
+        Rectangle::new(
+            (
+                (WINDOW_WIDTH as f32 - ROW_WIDTH) / 2.,
+                TOP + index as f32 * (ROW_HEIGHT + ROW_SPACING),
+            ),
+            (ROW_WIDTH, ROW_HEIGHT),
+        )
+    }
+
+    fn change_level(&mut self, delta: i32, context: &Context) {
+        let profile = match context.profiles.list().get(self.selected) {
+            Some(profile) => profile,
+            None => return,
+        };
+
+        let level = self.levels[self.selected] as i32 + delta;
+        if level >= 0 && profile.is_unlocked(level as u32) && LevelSpec::get(level as u32).is_some()
+        {
+            self.levels[self.selected] = level as u32;
+        }
+    }
+
+    fn activate(&mut self, index: usize, context: &mut Context) -> SceneChange {
+        if index < context.profiles.list().len() {
+            return self.choose(index, context);
+        }
+
+        self.new_profile = Some(NewProfile {
+            name: String::new(),
+            avatar: index % AVATARS.len(),
+        });
+        SceneChange::Stay
+    }
+
+    fn choose(&mut self, index: usize, context: &mut Context) -> SceneChange {
+        context.select_profile(index);
+        let level = context
+            .profiles
+            .word_for(self.levels[index])
+            .and_then(|word| {
+                Level::new(
+                    self.levels[index],
+                    &word,
+                    context.config.language,
+                    &context.assets,
+                )
+            });
+        let level = match level {
+            Some(level) => level,
+            None => return SceneChange::Stay,
+        };
+
+        self.chosen = true;
+        let loading = Loading::new(level, 0, context);
+        SceneChange::Transition(
+            Transition::new(Effect::Fade),
+            Box::new(SceneChange::Replace(Box::new(loading))),
+        )
+    }
+
+    fn edit(&mut self, window: &Window, context: &mut Context) -> SceneChange {
+        let keyboard = window.keyboard();
+        if keyboard[Key::Escape] == ButtonState::Pressed {
+            self.new_profile = None;
+            return SceneChange::Stay;
+        }
+
+        let create = match self.new_profile {
+            Some(ref mut new_profile) => {
+                for (index, key) in LETTERS.iter().enumerate() {
+                    if keyboard[*key] == ButtonState::Pressed
+                        && new_profile.name.len() < MAX_NAME_LENGTH
+                    {
+                        new_profile.name.push((b'A' + index as u8) as char);
+                    }
+                }
+
+                if keyboard[Key::Back] == ButtonState::Pressed {
+                    new_profile.name.pop();
+                }
+
+                if keyboard[Key::Left] == ButtonState::Pressed {
+                    new_profile.avatar = (new_profile.avatar + AVATARS.len() - 1) % AVATARS.len();
+                }
+
+                if keyboard[Key::Right] == ButtonState::Pressed {
+                    new_profile.avatar = (new_profile.avatar + 1) % AVATARS.len();
+                }
+
+                keyboard[Key::Return] == ButtonState::Pressed && !new_profile.name.is_empty()
+            }
+            None => false,
+        };
+
+        if !create {
+            return SceneChange::Stay;
+        }
+
+        let profile = match self.new_profile.take() {
+            Some(new_profile) => {
+                Profile::new(&new_profile.name, new_profile.avatar, context.config)
+            }
+            None => return SceneChange::Stay,
+        };
+        match context.profiles.add(profile) {
+            Some(index) => {
+                context.profiles.save();
+                self.levels.push(0);
+                self.choose(index, context)
+            }
+            None => SceneChange::Stay,
+        }
+    }
+
+    fn draw_row(
+        window: &mut Window,
+        context: &mut Context,
+        index: usize,
+        selected: bool,
+        avatar: Option<usize>,
+        name: &str,
+        value: &str,
+    ) -> Result<()> {
+        let area = Self::area(index);
+        let background = if selected { *SELECTED } else { *BUTTON };
+        window.draw_ex(&area, Col(background), Transform::IDENTITY, HUD_Z);
+
+        let mut left = area.x() + PADDING;
+        if let Some(avatar) = avatar {
+            let center = Vector::new(left + AVATAR_RADIUS, area.center().y);
+            window.draw_ex(
+                &Circle::new(center, AVATAR_RADIUS),
+                Col(AVATARS[avatar % AVATARS.len()]),
+                Transform::IDENTITY,
+                HUD_Z + 1,
+            );
+            let initial: String = name.chars().take(1).collect();
+            if let Some(initial_text) = context.text.render(&initial, &INITIAL)? {
+                window.draw_ex(
+                    &initial_text.area().with_center(center),
+                    Img(&initial_text),
+                    Transform::IDENTITY,
+                    HUD_Z + 2,
+                );
+            }
+            left += AVATAR_RADIUS * 2. + PADDING;
+        }
+
+        if let Some(name_text) = context.text.render(name, &ROW)? {
+            let name_area = name_text.area();
+            let position = Vector::new(left, area.center().y - name_area.height() / 2.);
+            window.draw_ex(
+                &name_area.translate(position),
+                Img(&name_text),
+                Transform::IDENTITY,
+                HUD_Z + 1,
+            );
+        }
+
+        if let Some(value_text) = context.text.render(value, &ROW)? {
+            let value_area = value_text.area();
+            let position = Vector::new(
+                area.x() + area.width() - PADDING - value_area.width(),
+                area.center().y - value_area.height() / 2.,
+            );
+            window.draw_ex(
+                &value_area.translate(position),
+                Img(&value_text),
+                Transform::IDENTITY,
+                HUD_Z + 1,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl Scene for ProfilePicker {
+    fn update(&mut self, window: &mut Window, context: &mut Context) -> Result<SceneChange> {
+        if self.chosen {
+            return Ok(SceneChange::Stay);
+        }
+
+        if self.new_profile.is_some() {
+            return Ok(self.edit(window, context));
+        }
+
+        let rows = self.rows(context);
+        let keyboard = window.keyboard();
+        if keyboard[Key::Up] == ButtonState::Pressed {
+            self.selected = (self.selected + rows - 1) % rows;
+        }
+
+        if keyboard[Key::Down] == ButtonState::Pressed {
+            self.selected = (self.selected + 1) % rows;
+        }
+
+        if keyboard[Key::Left] == ButtonState::Pressed {
+            self.change_level(-1, context);
+        }
+
+        if keyboard[Key::Right] == ButtonState::Pressed {
+            self.change_level(1, context);
+        }
+
+        if keyboard[Key::Return] == ButtonState::Pressed {
+            return Ok(self.activate(self.selected, context));
+        }
+
+        let pointer = Display::pointer(window);
+        if let Some(index) = (0..rows).find(|index| Self::area(*index).contains(pointer)) {
+            self.selected = index;
+            if window.mouse()[MouseButton::Left] == ButtonState::Released {
+                return Ok(self.activate(index, context));
+            }
+        }
+
+        Ok(SceneChange::Stay)
+    }
+
+    fn draw(&mut self, window: &mut Window, context: &mut Context) -> Result<()> {
+        let language = context.config.language;
+        if let Some(title_text) = context
+            .text
+            .render(language.text(Message::WhoIsPlaying), &TITLE)?
+        {
+            window.draw_ex(
+                &title_text
+                    .area()
+                    .with_center(Vector::new(WINDOW_WIDTH as f32 / 2., TOP / 2.)),
+                Img(&title_text),
+                Transform::IDENTITY,
+                HUD_Z,
+            );
+        }
+
+        let profiles: Vec<(usize, String)> = context
+            .profiles
+            .list()
+            .iter()
+            .map(|profile| (profile.avatar, profile.name.clone()))
+            .collect();
+        for (index, (avatar, name)) in profiles.into_iter().enumerate() {
+            let value = format!(
+                "{} {}",
+                language.text(Message::Level),
+                self.levels[index] + 1
+            );
+            let selected = index == self.selected && self.new_profile.is_none();
+            Self::draw_row(
+                window,
+                context,
+                index,
+                selected,
+                Some(avatar),
+                &name,
+                &value,
+            )?;
+        }
+
+        let index = context.profiles.list().len();
+        let hint = match self.new_profile {
+            Some(ref new_profile) => {
+                let name = format!("{}_", new_profile.name);
+                let length = format!("{}/{}", new_profile.name.len(), MAX_NAME_LENGTH);
+                Self::draw_row(
+                    window,
+                    context,
+                    index,
+                    true,
+                    Some(new_profile.avatar),
+                    &name,
+                    &length,
+                )?;
+                language.text(Message::NameHint)
+            }
+            None if context.profiles.is_full() => language.text(Message::LevelHint),
+            None => {
+                let selected = index == self.selected;
+                let new_player = language.text(Message::NewPlayer);
+                Self::draw_row(window, context, index, selected, None, new_player, "+")?;
+                language.text(Message::LevelHint)
+            }
+        };
+
+        if let Some(hint_text) = context.text.render(hint, &HINT)? {
+            window.draw_ex(
+                &hint_text
+                    .area()
+                    .with_center((WINDOW_WIDTH / 2, WINDOW_HEIGHT - 24)),
+                Img(&hint_text),
+                Transform::IDENTITY,
+                HUD_Z,
+            );
+        }
+
+        Ok(())
+    }
+}
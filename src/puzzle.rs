@@ -1,25 +1,5 @@
-use constant;
-use quicksilver::{
-    geom::{Shape, Transform, Vector},
-    graphics::{Background::Img, Color, Font, FontStyle},
-    lifecycle::{Asset, Window},
-    sound::Sound,
-    Result,
-};
-
-lazy_static! {
-    static ref BIG: FontStyle = FontStyle::new(42.0, Color::WHITE);
-    static ref NORMAL: FontStyle = FontStyle::new(24.0, Color::WHITE);
-}
-
 pub struct Puzzle {
-    tick: f64,
     pub answer: Vec<Answered>,
-    pub font: Asset<Font>,
-    pub instruction_sound: Asset<Sound>,
-    pub answer_sound: Asset<Sound>,
-    instruction_played_timestamp: f64,
-    word_played_timestamp: f64,
 }
 
 pub enum Answered {
@@ -36,91 +16,40 @@ impl Answered {
     }
 }
 
+#[derive(Debug, PartialEq)]
 pub enum CanCollect {
     Yes,
     No,
 }
 
 impl Puzzle {
-    pub fn new(word: String) -> Self {
-        let font = Asset::new(Font::load("resources/fonts/slkscr.ttf"));
+    pub fn new(word: &str) -> Self {
         let answer = word.chars().map(|letter| Answered::No(letter)).collect();
-        let instruction_sound = Asset::new(Sound::load("resources/sounds/instructions.mp3"));
-        let answer_sound = Asset::new(Sound::load(format!(
-            "resources/sounds/{}.mp3",
-            word.to_ascii_lowercase()
-        )));
-        Puzzle {
-            tick: 0.,
-            font,
-            answer,
-            instruction_sound,
-            instruction_played_timestamp: 0.,
-            word_played_timestamp: 0.,
-            answer_sound,
-        }
+        Puzzle { answer }
     }
 
-    pub fn update(&mut self, window: &mut Window) -> Result<()> {
-        self.tick = self.tick + window.update_rate();
-        let instruction_sound = &mut self.instruction_sound;
-        let answer_sound = &mut self.answer_sound;
-        if self.instruction_played_timestamp <= 0. {
-            self.instruction_played_timestamp = self.tick;
-            instruction_sound.execute(|instruction| {
-                instruction.play()?;
-                Ok(())
-            })?;
-        }
-
-        if self.word_played_timestamp <= 0.
-            && self.tick - self.instruction_played_timestamp >= 3000.
-        {
-            self.word_played_timestamp = self.tick;
-            answer_sound.execute(|answer| {
-                answer.play()?;
-                Ok(())
-            })?;
+    pub fn reset(&mut self) {
+        for answer in self.answer.iter_mut() {
+            if let Answered::Yes(letter) = *answer {
+                *answer = Answered::No(letter);
+            }
         }
+    }
 
-        Ok(())
+    pub fn rendered(&self) -> String {
+        self.answer
+            .iter()
+            .map(|letter| letter.to_rendered_char())
+            .collect()
     }
 
-    pub fn draw(&mut self, window: &mut Window) -> Result<()> {
-        let answer = &self.answer;
-        self.font.execute(|font| {
-            let instruction_text = font.render("Collect all the letters for the word:", &NORMAL)?;
-            let instruction_height = instruction_text.area().height() as u32;
-            let answer_to_render: String = answer
-                .iter()
-                .map(|letter| letter.to_rendered_char())
-                .collect();
-
-            let answer_text = font.render(&answer_to_render, &BIG)?;
-            let answer_height = answer_text.area().height() as u32;
-            window.draw_ex(
-                &instruction_text.area().with_center((
-                    constant::WINDOW_WIDTH / 2,
-                    constant::WINDOW_HEIGHT - (instruction_height + answer_height),
-                )),
-                Img(&instruction_text),
-                Transform::scale(Vector::new(1, 1)),
-                4,
-            );
-
-            window.draw_ex(
-                &answer_text.area().with_center((
-                    constant::WINDOW_WIDTH / 2,
-                    constant::WINDOW_HEIGHT - (answer_height * 2 / 3),
-                )),
-                Img(&answer_text),
-                Transform::scale(Vector::new(1, 1)),
-                4,
-            );
-            Ok(())
-        })?;
-
-        Ok(())
+    pub fn word(&self) -> String {
+        self.answer
+            .iter()
+            .map(|answer| match *answer {
+                Answered::Yes(letter) | Answered::No(letter) => letter,
+            })
+            .collect()
     }
 
     pub fn collect(&mut self, letter: char) -> CanCollect {
@@ -134,6 +63,16 @@ impl Puzzle {
         }
     }
 
+    pub fn next_slot(&self) -> usize {
+        self.answer
+            .iter()
+            .position(|answer| match *answer {
+                Answered::No(_) => true,
+                Answered::Yes(_) => false,
+            })
+            .unwrap_or(self.answer.len())
+    }
+
     pub fn is_solved(&self) -> bool {
         for answer in self.answer.iter() {
             if let Answered::No(_) = answer {
@@ -158,3 +97,41 @@ impl Puzzle {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letters_must_be_collected_in_order() {
+        let mut puzzle = Puzzle::new("APPLE");
+        assert_eq!(puzzle.collect('P'), CanCollect::No);
+        assert_eq!(puzzle.collect('A'), CanCollect::Yes);
+        assert_eq!(puzzle.collect('A'), CanCollect::No);
+        assert_eq!(puzzle.collect('P'), CanCollect::Yes);
+        assert_eq!(puzzle.rendered(), "AP___");
+        assert_eq!(puzzle.next_slot(), 2);
+    }
+
+    #[test]
+    fn repeated_letters_fill_one_slot_each() {
+        let mut puzzle = Puzzle::new("APPLE");
+        for letter in "APPLE".chars() {
+            assert_eq!(puzzle.collect(letter), CanCollect::Yes);
+        }
+        assert!(puzzle.is_solved());
+        assert_eq!(puzzle.next_slot(), 5);
+        assert_eq!(puzzle.collect('E'), CanCollect::No);
+    }
+
+    #[test]
+    fn reset_keeps_the_word() {
+        let mut puzzle = Puzzle::new("AB");
+        puzzle.collect('A');
+        puzzle.collect('B');
+        puzzle.reset();
+        assert!(!puzzle.is_solved());
+        assert_eq!(puzzle.rendered(), "__");
+        assert_eq!(puzzle.word(), "AB");
+    }
+}
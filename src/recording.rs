@@ -0,0 +1,228 @@
+use clock;
+use export;
+use primitive::Position;
+use quicksilver::saving::{load, save};
+use serde_json;
+use simulation::{Input, Simulation};
+use terrain::Terrain;
+
+const APP_NAME: &str = "roborex";
+const SESSION_PROFILE: &str = "session";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Span {
+    repeat: u32,
+    input: Input,
+    walking_speed: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    pub level: u32,
+    pub word: String,
+    spans: Vec<Span>,
+}
+
+impl Recording {
+    pub fn new(level: u32, word: &str) -> Self {
+        Recording {
+            level,
+            word: word.to_string(),
+            spans: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, input: &Input, walking_speed: f64) {
+        if let Some(span) = self.spans.last_mut() {
+            if span.input == *input && span.walking_speed == walking_speed {
+                span.repeat += 1;
+                return;
+            }
+        }
+
+        self.spans.push(Span {
+            repeat: 1,
+            input: input.clone(),
+            walking_speed,
+        });
+    }
+
+    pub fn replay(&self) -> Replay {
+        Replay {
+            spans: self.spans.clone(),
+            span: 0,
+            repeat: 0,
+        }
+    }
+}
+
+pub struct Replay {
+    spans: Vec<Span>,
+    span: usize,
+    repeat: u32,
+}
+
+impl Iterator for Replay {
+    type Item = (Input, f64);
+
+    fn next(&mut self) -> Option<(Input, f64)> {
+        let span = self.spans.get(self.span)?;
+        let step = (span.input.clone(), span.walking_speed);
+        self.repeat += 1;
+        if self.repeat >= span.repeat {
+            self.span += 1;
+            self.repeat = 0;
+        }
+
+        Some(step)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Outcome {
+    pub position: Position,
+    pub score: u32,
+    pub solved: bool,
+    pub gate_open: bool,
+}
+
+impl Outcome {
+    pub fn new(simulation: &Simulation, terrain: &Terrain) -> Self {
+        Outcome {
+            position: simulation.movement.position.clone(),
+            score: simulation.score(),
+            solved: simulation.puzzle.is_solved(),
+            gate_open: !terrain.gate().is_closed(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Session {
+    pub recording: Recording,
+    pub outcome: Outcome,
+}
+
+impl Session {
+    pub fn load() -> Option<Session> {
+        load(APP_NAME, SESSION_PROFILE).ok()
+    }
+
+    pub fn save(&self) -> bool {
+        save(APP_NAME, SESSION_PROFILE, self).is_ok()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    pub fn export(&self) -> Option<String> {
+        let file_name = format!("roborex-session-{}.json", clock::now() as u64);
+        export::write(&file_name, &self.to_json(), "application/json")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clock::STEP;
+    use level_spec::LevelSpec;
+    use std::fs::{self, File};
+    use std::path::Path;
+    use tiled;
+
+    fn run(recording: &Recording, terrain: &mut Terrain) -> Option<Outcome> {
+        let mut simulation = LevelSpec::get(recording.level)?
+            .with_word(&recording.word)?
+            .simulation();
+        for (input, walking_speed) in recording.replay() {
+            simulation.movement.set_walking_speed(walking_speed);
+            simulation.step(&input, STEP, terrain);
+        }
+
+        Some(Outcome::new(&simulation, terrain))
+    }
+
+    fn terrain(level: u32) -> Terrain {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let spec = LevelSpec::get(level).unwrap();
+        let map = tiled::parse_file(&root.join("static").join(spec.map)).unwrap();
+        Terrain::from_map(&map, spec.gate_position)
+    }
+
+    #[test]
+    fn repeated_inputs_are_stored_once() {
+        let mut recording = Recording::new(0, "APPLE");
+        let right = Input {
+            right: true,
+            ..Input::default()
+        };
+        for _ in 0..3 {
+            recording.record(&right, 1.);
+        }
+        recording.record(&Input::default(), 1.);
+        recording.record(&right, 1.);
+        recording.record(&right, 2.);
+
+        assert_eq!(recording.spans.len(), 4);
+        let replayed: Vec<(Input, f64)> = recording.replay().collect();
+        assert_eq!(
+            replayed,
+            vec![
+                (right.clone(), 1.),
+                (right.clone(), 1.),
+                (right.clone(), 1.),
+                (Input::default(), 1.),
+                (right.clone(), 1.),
+                (right, 2.),
+            ]
+        );
+    }
+
+    #[test]
+    fn replaying_twice_gives_the_same_outcome() {
+        let mut recording = Recording::new(1, "JONATHAN");
+        let right = Input {
+            right: true,
+            ..Input::default()
+        };
+        for _ in 0..400 {
+            recording.record(&right, 1.25);
+        }
+
+        let first = run(&recording, &mut terrain(1));
+        let second = run(&recording, &mut terrain(1));
+        assert!(first.is_some());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn saved_sessions_replay_to_their_recorded_outcome() {
+        let sessions = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/sessions");
+        let mut replayed = 0;
+        for entry in fs::read_dir(sessions).unwrap() {
+            let path = entry.unwrap().path();
+            let session: Session = serde_json::from_reader(File::open(&path).unwrap()).unwrap();
+            let mut terrain = terrain(session.recording.level);
+            assert_eq!(
+                run(&session.recording, &mut terrain),
+                Some(session.outcome),
+                "{}",
+                path.display()
+            );
+            replayed += 1;
+        }
+
+        assert!(replayed > 0);
+    }
+
+    #[test]
+    fn exported_sessions_can_be_used_as_fixtures() {
+        let fixture =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/sessions/level1-solve.json");
+        let session: Session = serde_json::from_reader(File::open(fixture).unwrap()).unwrap();
+        let exported: Session = serde_json::from_str(&session.to_json()).unwrap();
+        assert_eq!(exported.recording, session.recording);
+        assert_eq!(exported.outcome, session.outcome);
+    }
+}
@@ -0,0 +1,265 @@
+use accessibility::Accessibility;
+use assets::Assets;
+use audio::Audio;
+use config::Config;
+use debug_overlay::DebugOverlay;
+use display::Display;
+use event_log::{EventLog, Format};
+use locale::Message;
+use music::Music;
+use notice::Notice;
+use profile::Profiles;
+use quicksilver::{
+    input::{ButtonState, Key},
+    lifecycle::Window,
+    Result,
+};
+use text::Text;
+use transition::Transition;
+
+pub struct Context {
+    pub assets: Assets,
+    pub display: Display,
+    pub text: Text,
+    pub audio: Audio,
+    pub music: Music,
+    pub notice: Notice,
+    pub debug: DebugOverlay,
+    pub accessibility: Accessibility,
+    pub config: Config,
+    pub event_log: EventLog,
+    pub profiles: Profiles,
+    saved_config: Config,
+    recorded: usize,
+}
+
+impl Context {
+    pub fn new(assets: Assets) -> Self {
+        let config = Config::load();
+        let mut text = Text::new(&assets);
+        text.set_accessibility(config.accessibility);
+        let mut audio = Audio::new(config.audio);
+        audio.captions().set_enabled(config.captions);
+
+        Context {
+            display: Display::new(),
+            text,
+            audio,
+            music: Music::new(&assets),
+            notice: Notice::new(),
+            debug: DebugOverlay::new(),
+            accessibility: config.accessibility,
+            config,
+            saved_config: config,
+            event_log: EventLog::new(),
+            profiles: Profiles::load(),
+            recorded: 0,
+            assets,
+        }
+    }
+
+    pub fn select_profile(&mut self, index: usize) {
+        let config = match self.profiles.select(index) {
+            Some(profile) => {
+                self.event_log.set_profile(&profile.name);
+                profile.config
+            }
+            None => return,
+        };
+
+        self.text.set_accessibility(config.accessibility);
+        self.audio.set_settings(config.audio);
+        self.audio.captions().set_enabled(config.captions);
+        self.accessibility = config.accessibility;
+        self.config = config;
+        self.saved_config = config;
+    }
+
+    pub fn export_event_log(&mut self, window: &Window) {
+        let keyboard = window.keyboard();
+        if keyboard[Key::F4] != ButtonState::Pressed {
+            return;
+        }
+
+        let format = if keyboard[Key::LShift].is_down() || keyboard[Key::RShift].is_down() {
+            Format::Json
+        } else {
+            Format::Csv
+        };
+        let language = self.config.language;
+        let message = match self.event_log.export(format) {
+            Some(location) => format!("{} {}", language.text(Message::EventLogSaved), location),
+            None => language.text(Message::EventLogNotSaved).to_string(),
+        };
+        self.notice.show(&message);
+    }
+
+    pub fn save_config(&mut self) {
+        let config = Config {
+            audio: self.audio.settings(),
+            captions: self.audio.captions().is_enabled(),
+            accessibility: self.accessibility,
+            ..self.config
+        };
+        self.config = config;
+        if config != self.saved_config {
+            self.saved_config = config;
+            if let Some(profile) = self.profiles.current_mut() {
+                profile.config = config;
+                self.profiles.save();
+            } else {
+                config.save();
+            }
+        }
+    }
+
+    pub fn record_progress(&mut self) {
+        let entries = self.event_log.since(self.recorded);
+        if entries.is_empty() {
+            return;
+        }
+
+        self.recorded += entries.len();
+        if let Some(profile) = self.profiles.current_mut() {
+            for entry in entries {
+                profile.record(entry);
+            }
+            self.profiles.save();
+        }
+    }
+}
+
+pub enum SceneResult {
+    Resumed,
+    Restart,
+    QuitToTitle,
+}
+
+pub enum SceneChange {
+    Stay,
+    Push(Box<dyn Scene>),
+    Pop(SceneResult),
+    Replace(Box<dyn Scene>),
+    Transition(Transition, Box<SceneChange>),
+}
+
+pub trait Scene {
+    fn update(&mut self, window: &mut Window, context: &mut Context) -> Result<SceneChange>;
+
+    fn draw(&mut self, window: &mut Window, context: &mut Context) -> Result<()>;
+
+    fn resume(&mut self, _result: SceneResult, _context: &mut Context) -> Result<SceneChange> {
+        Ok(SceneChange::Stay)
+    }
+
+    fn is_overlay(&self) -> bool {
+        false
+    }
+
+    fn updates_during_transition(&self) -> bool {
+        false
+    }
+}
+
+pub struct SceneStack {
+    scenes: Vec<Box<dyn Scene>>,
+    transition: Option<Transition>,
+    pending: Option<SceneChange>,
+}
+
+impl SceneStack {
+    pub fn new(scene: Box<dyn Scene>) -> Self {
+        SceneStack {
+            scenes: vec![scene],
+            transition: None,
+            pending: None,
+        }
+    }
+
+    pub fn update(&mut self, window: &mut Window, context: &mut Context) -> Result<()> {
+        if self.transition.is_some() {
+            return self.update_transition(window, context);
+        }
+
+        let change = match self.scenes.last_mut() {
+            Some(scene) => scene.update(window, context)?,
+            None => SceneChange::Stay,
+        };
+
+        self.apply(change, context)
+    }
+
+    pub fn draw(&mut self, window: &mut Window, context: &mut Context) -> Result<()> {
+        let bottom = self
+            .scenes
+            .iter()
+            .rposition(|scene| !scene.is_overlay())
+            .unwrap_or(0);
+        for scene in self.scenes[bottom..].iter_mut() {
+            scene.draw(window, context)?;
+        }
+
+        if let Some(ref mut transition) = self.transition {
+            transition.draw(window, &mut context.text)?;
+        }
+
+        Ok(())
+    }
+
+    fn update_transition(&mut self, window: &mut Window, context: &mut Context) -> Result<()> {
+        let mut covered = false;
+        let mut done = false;
+        if let Some(ref mut transition) = self.transition {
+            covered = transition.update(window.update_rate());
+            done = transition.is_done();
+        }
+
+        if covered {
+            if let Some(change) = self.pending.take() {
+                self.apply(change, context)?;
+            }
+        }
+
+        if done {
+            self.transition = None;
+        }
+
+        let change = match self.scenes.last_mut() {
+            Some(ref mut scene) if scene.updates_during_transition() => {
+                scene.update(window, context)?
+            }
+            _ => SceneChange::Stay,
+        };
+
+        self.apply(change, context)
+    }
+
+    fn apply(&mut self, change: SceneChange, context: &mut Context) -> Result<()> {
+        match change {
+            SceneChange::Stay => {}
+            SceneChange::Push(scene) => self.scenes.push(scene),
+            SceneChange::Pop(result) => {
+                self.scenes.pop();
+                let change = match self.scenes.last_mut() {
+                    Some(scene) => scene.resume(result, context)?,
+                    None => SceneChange::Stay,
+                };
+                self.apply(change, context)?;
+            }
+            SceneChange::Replace(scene) => {
+                self.scenes.pop();
+                self.scenes.push(scene);
+            }
+            SceneChange::Transition(transition, change) => {
+                self.transition = Some(if context.accessibility.reduced_motion {
+                    transition.with_reduced_motion()
+                } else {
+                    transition
+                });
+                self.pending = Some(*change);
+            }
+        }
+
+        Ok(())
+    }
+}
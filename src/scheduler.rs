@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+const INTERVALS: [u32; 5] = [1, 2, 4, 8, 16];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Card {
+    pub leitner_box: usize,
+    pub due: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Scheduler {
+    round: u32,
+    cards: BTreeMap<String, Card>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Scheduler::default()
+    }
+
+    pub fn review(&mut self, word: &str, mistakes: u32) {
+        self.round += 1;
+        let round = self.round;
+        let card = self.cards.entry(word.to_string()).or_insert(Card {
+            leitner_box: 0,
+            due: round,
+        });
+        card.leitner_box = if mistakes > 0 {
+            0
+        } else {
+            (card.leitner_box + 1).min(INTERVALS.len() - 1)
+        };
+        card.due = round + INTERVALS[card.leitner_box];
+    }
+
+    pub fn choose(&self, words: &[&'static str]) -> Option<&'static str> {
+        words
+            .iter()
+            .enumerate()
+            .min_by_key(|(index, word)| match self.cards.get(**word) {
+                Some(card) if card.due <= self.round => (0, card.due, card.leitner_box, *index),
+                None => (1, 0, 0, *index),
+                Some(card) => (2, card.due, card.leitner_box, *index),
+            })
+            .map(|(_, word)| *word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_words_are_introduced_in_bank_order() {
+        let scheduler = Scheduler::new();
+        assert_eq!(scheduler.choose(&["CAT", "DOG"]), Some("CAT"));
+        assert_eq!(scheduler.choose(&[]), None);
+    }
+
+    #[test]
+    fn mastered_words_come_back_later() {
+        let mut scheduler = Scheduler::new();
+        scheduler.review("CAT", 0);
+        assert_eq!(scheduler.cards["CAT"].due, 3);
+        assert_eq!(scheduler.choose(&["CAT", "DOG"]), Some("DOG"));
+
+        scheduler.review("CAT", 0);
+        scheduler.review("CAT", 0);
+        let card = &scheduler.cards["CAT"];
+        assert_eq!(card.leitner_box, 3);
+        assert_eq!(card.due, 3 + INTERVALS[3]);
+    }
+
+    #[test]
+    fn misspelled_words_come_back_next_round() {
+        let mut scheduler = Scheduler::new();
+        scheduler.review("CAT", 0);
+        scheduler.review("CAT", 0);
+        scheduler.review("CAT", 2);
+        let card = &scheduler.cards["CAT"];
+        assert_eq!(card.leitner_box, 0);
+        assert_eq!(card.due, 4);
+
+        scheduler.review("DOG", 0);
+        assert_eq!(scheduler.choose(&["DOG", "CAT", "EMU"]), Some("CAT"));
+    }
+
+    #[test]
+    fn upcoming_words_are_chosen_when_nothing_else_is_left() {
+        let mut scheduler = Scheduler::new();
+        scheduler.review("CAT", 0);
+        scheduler.review("CAT", 0);
+        scheduler.review("DOG", 0);
+        assert_eq!(scheduler.choose(&["CAT", "DOG"]), Some("DOG"));
+    }
+}
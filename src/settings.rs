@@ -0,0 +1,290 @@
+use accessibility::TEXT_SCALES;
+use audio::Channel;
+use config::ControlScheme;
+use constant::{HUD_Z, WINDOW_WIDTH};
+use display::Display;
+use locale::Message;
+use quicksilver::{
+    geom::{Rectangle, Shape, Transform, Vector},
+    graphics::{Background::Col, Background::Img, Color},
+    input::{ButtonState, Key, MouseButton},
+    lifecycle::Window,
+    Result,
+};
+use scene::{Context, Scene, SceneChange, SceneResult};
+use text::TextStyle;
+
+const WALKING_SPEEDS: [f32; 6] = [0.5, 0.75, 1., 1.25, 1.5, 2.];
+const VOLUMES: [f32; 11] = [0., 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.];
+const ROW_WIDTH: f32 = 480.;
+const ROW_HEIGHT: f32 = 40.;
+const ROW_SPACING: f32 = 8.;
+const TOP: f32 = 110.;
+const PADDING: f32 = 16.;
+
+lazy_static! {
+    static ref TITLE: TextStyle = TextStyle::new(42.0, Color::WHITE);
+    static ref ROW: TextStyle = TextStyle::new(24.0, Color::WHITE);
+    static ref BUTTON: Color = Color::BLACK.with_alpha(0.8);
+    static ref SELECTED: Color = Color::WHITE.with_alpha(0.3);
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Setting {
+    WalkingSpeed,
+    Volume(Option<Channel>),
+    Captions,
+    TextSize,
+    Controls,
+    Language,
+    Back,
+}
+
+const SETTINGS: [Setting; 10] = [
+    Setting::WalkingSpeed,
+    Setting::Volume(None),
+    Setting::Volume(Some(Channel::Voice)),
+    Setting::Volume(Some(Channel::Music)),
+    Setting::Volume(Some(Channel::Sfx)),
+    Setting::Captions,
+    Setting::TextSize,
+    Setting::Controls,
+    Setting::Language,
+    Setting::Back,
+];
+
+impl Setting {
+    fn label(&self) -> Message {
+        match self {
+            Setting::WalkingSpeed => Message::WalkingSpeed,
+            Setting::Volume(None) => Message::Volume,
+            Setting::Volume(Some(Channel::Voice)) => Message::Voice,
+            Setting::Volume(Some(Channel::Music)) => Message::Music,
+            Setting::Volume(Some(Channel::Sfx)) => Message::Effects,
+            Setting::Captions => Message::Captions,
+            Setting::TextSize => Message::TextSize,
+            Setting::Controls => Message::Controls,
+            Setting::Language => Message::Language,
+            Setting::Back => Message::Back,
+        }
+    }
+
+    fn value(&self, context: &mut Context) -> String {
+        let audio = context.audio.settings();
+        let language = context.config.language;
+        let percent = |value: f32| format!("{:.0}%", value * 100.);
+        match self {
+            Setting::WalkingSpeed => format!("{}x", context.config.walking_speed),
+            Setting::Volume(None) => percent(audio.master),
+            Setting::Volume(Some(Channel::Voice)) => percent(audio.voice),
+            Setting::Volume(Some(Channel::Music)) => percent(audio.music),
+            Setting::Volume(Some(Channel::Sfx)) => percent(audio.sfx),
+            Setting::Captions if context.audio.captions().is_enabled() => {
+                language.text(Message::On).to_string()
+            }
+            Setting::Captions => language.text(Message::Off).to_string(),
+            Setting::TextSize => percent(context.accessibility.text_scale),
+            Setting::Controls => language
+                .text(match context.config.controls {
+                    ControlScheme::Both => Message::KeyboardAndMouse,
+                    ControlScheme::Keyboard => Message::Keyboard,
+                    ControlScheme::Mouse => Message::Mouse,
+                })
+                .to_string(),
+            Setting::Language => language.name().to_string(),
+            Setting::Back => String::new(),
+        }
+    }
+
+    fn adjust(&self, delta: i32, context: &mut Context) {
+        let audio = context.audio.settings();
+        match self {
+            Setting::WalkingSpeed => {
+                let speed = cycle(&WALKING_SPEEDS, context.config.walking_speed, delta);
+                context.config.walking_speed = speed;
+            }
+            Setting::Volume(channel) => {
+                let volume = match channel {
+                    None => audio.master,
+                    Some(Channel::Voice) => audio.voice,
+                    Some(Channel::Music) => audio.music,
+                    Some(Channel::Sfx) => audio.sfx,
+                };
+                context
+                    .audio
+                    .set_volume(*channel, cycle(&VOLUMES, volume, delta));
+            }
+            Setting::Captions => {
+                let captions = context.audio.captions();
+                let enabled = captions.is_enabled();
+                captions.set_enabled(!enabled);
+            }
+            Setting::TextSize => {
+                let scale = cycle(&TEXT_SCALES, context.accessibility.text_scale, delta);
+                context.accessibility.text_scale = scale;
+                context.text.set_accessibility(context.accessibility);
+            }
+            Setting::Controls => {
+                context.config.controls = match (context.config.controls, delta > 0) {
+                    (ControlScheme::Both, true) | (ControlScheme::Mouse, false) => {
+                        ControlScheme::Keyboard
+                    }
+                    (ControlScheme::Keyboard, true) | (ControlScheme::Both, false) => {
+                        ControlScheme::Mouse
+                    }
+                    (ControlScheme::Mouse, true) | (ControlScheme::Keyboard, false) => {
+                        ControlScheme::Both
+                    }
+                }
+            }
+            Setting::Language => {
+                context.config.language = context.config.language.cycle(delta);
+            }
+            Setting::Back => {}
+        }
+    }
+
+    fn area(index: usize) -> Rectangle {
+        Rectangle::new(
+            (
+                (WINDOW_WIDTH as f32 - ROW_WIDTH) / 2.,
+                TOP + index as f32 * (ROW_HEIGHT + ROW_SPACING),
+            ),
+            (ROW_WIDTH, ROW_HEIGHT),
+        )
+    }
+}
+
+fn cycle(values: &[f32], current: f32, delta: i32) -> f32 {
+    let nearest = values
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            (*a - current)
+                .abs()
+                .partial_cmp(&(*b - current).abs())
+                .unwrap()
+        })
+        .map(|(index, _)| index as i32)
+        .unwrap_or(0);
+    let len = values.len() as i32;
+    values[(((nearest + delta) % len + len) % len) as usize]
+}
+
+pub struct Settings {
+    selected: usize,
+}
+
+impl Settings {
+    pub fn new() -> Self {
+        Settings { selected: 0 }
+    }
+
+    fn activate(&mut self, index: usize, context: &mut Context) -> SceneChange {
+        match SETTINGS[index] {
+            Setting::Back => SceneChange::Pop(SceneResult::Resumed),
+            setting => {
+                setting.adjust(1, context);
+                SceneChange::Stay
+            }
+        }
+    }
+}
+
+impl Scene for Settings {
+    fn update(&mut self, window: &mut Window, context: &mut Context) -> Result<SceneChange> {
+        let rows = SETTINGS.len();
+        let keyboard = window.keyboard();
+        if keyboard[Key::Escape] == ButtonState::Pressed {
+            return Ok(SceneChange::Pop(SceneResult::Resumed));
+        }
+
+        if keyboard[Key::Up] == ButtonState::Pressed {
+            self.selected = (self.selected + rows - 1) % rows;
+        }
+
+        if keyboard[Key::Down] == ButtonState::Pressed {
+            self.selected = (self.selected + 1) % rows;
+        }
+
+        if keyboard[Key::Left] == ButtonState::Pressed {
+            SETTINGS[self.selected].adjust(-1, context);
+        }
+
+        if keyboard[Key::Right] == ButtonState::Pressed {
+            SETTINGS[self.selected].adjust(1, context);
+        }
+
+        if keyboard[Key::Return] == ButtonState::Pressed {
+            return Ok(self.activate(self.selected, context));
+        }
+
+        let pointer = Display::pointer(window);
+        if let Some(index) = (0..rows).find(|index| Setting::area(*index).contains(pointer)) {
+            self.selected = index;
+            if window.mouse()[MouseButton::Left] == ButtonState::Released {
+                return Ok(self.activate(index, context));
+            }
+        }
+
+        Ok(SceneChange::Stay)
+    }
+
+    fn draw(&mut self, window: &mut Window, context: &mut Context) -> Result<()> {
+        let language = context.config.language;
+        if let Some(title_text) = context
+            .text
+            .render(language.text(Message::Settings), &TITLE)?
+        {
+            window.draw_ex(
+                &title_text
+                    .area()
+                    .with_center(Vector::new(WINDOW_WIDTH as f32 / 2., TOP / 2.)),
+                Img(&title_text),
+                Transform::IDENTITY,
+                HUD_Z,
+            );
+        }
+
+        for (index, setting) in SETTINGS.iter().enumerate() {
+            let area = Setting::area(index);
+            let background = if index == self.selected {
+                *SELECTED
+            } else {
+                *BUTTON
+            };
+            window.draw_ex(&area, Col(background), Transform::IDENTITY, HUD_Z);
+
+            if let Some(label) = context.text.render(language.text(setting.label()), &ROW)? {
+                let label_area = label.area();
+                let position = Vector::new(
+                    area.x() + PADDING,
+                    area.center().y - label_area.height() / 2.,
+                );
+                window.draw_ex(
+                    &label_area.translate(position),
+                    Img(&label),
+                    Transform::IDENTITY,
+                    HUD_Z + 1,
+                );
+            }
+
+            let value = setting.value(context);
+            if let Some(value_text) = context.text.render(&value, &ROW)? {
+                let value_area = value_text.area();
+                let position = Vector::new(
+                    area.x() + area.width() - PADDING - value_area.width(),
+                    area.center().y - value_area.height() / 2.,
+                );
+                window.draw_ex(
+                    &value_area.translate(position),
+                    Img(&value_text),
+                    Transform::IDENTITY,
+                    HUD_Z + 1,
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,229 @@
+use collectible::Collectible;
+use direction::Direction;
+use movement::Movement;
+use primitive::Position;
+use puzzle::{CanCollect, Puzzle};
+use terrain::Terrain;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    Footstep,
+    Bump,
+    Collected(char, usize),
+    Rejected(char, usize),
+    GateOpened,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Input {
+    pub left: bool,
+    pub right: bool,
+    pub up: bool,
+    pub down: bool,
+    pub target: Option<Position>,
+}
+
+impl Input {
+    pub fn directions(&self) -> Vec<Direction> {
+        let mut directions = Vec::new();
+        if self.right {
+            directions.push(Direction::Right);
+        }
+
+        if self.left {
+            directions.push(Direction::Left);
+        }
+
+        if self.up {
+            directions.push(Direction::Up);
+        }
+
+        if self.down {
+            directions.push(Direction::Down);
+        }
+
+        directions
+    }
+}
+
+pub struct Simulation {
+    pub movement: Movement,
+    pub puzzle: Puzzle,
+    pub collectibles: Vec<Collectible>,
+    start_position: Position,
+    score: u32,
+    rejected: Option<Position>,
+}
+
+impl Simulation {
+    pub fn new(word: &str, collectibles: Vec<Collectible>, start_position: Position) -> Self {
+        Simulation {
+            movement: Movement::new(start_position.clone()),
+            puzzle: Puzzle::new(word),
+            collectibles,
+            start_position,
+            score: 0,
+            rejected: None,
+        }
+    }
+
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    pub fn is_at_gate(&self, terrain: &Terrain) -> bool {
+        terrain.gate_position() == &self.movement.position
+    }
+
+    pub fn restart(&mut self, terrain: &mut Terrain) {
+        let walking_speed = self.movement.walking_speed();
+        self.movement = Movement::new(self.start_position.clone());
+        self.movement.set_walking_speed(walking_speed);
+        self.puzzle.reset();
+        for collectible in self.collectibles.iter_mut() {
+            collectible.reset();
+        }
+        self.score = 0;
+        self.rejected = None;
+        terrain.close_gate();
+    }
+
+    pub fn step(&mut self, input: &Input, elapsed: f64, terrain: &mut Terrain) -> Vec<Event> {
+        let mut events = Vec::new();
+        self.movement.update(input, elapsed, terrain, &mut events);
+
+        let mut colliding = false;
+        for collectible in self.collectibles.iter_mut() {
+            if collectible.collide_with(&self.movement.position) {
+                colliding = true;
+                let slot = self.puzzle.next_slot();
+                match self.puzzle.collect(collectible.letter) {
+                    CanCollect::Yes => {
+                        collectible.collect();
+                        self.score += 1;
+                        events.push(Event::Collected(collectible.letter, slot));
+                    }
+                    CanCollect::No if self.rejected.as_ref() != Some(&collectible.position) => {
+                        self.rejected = Some(collectible.position.clone());
+                        events.push(Event::Rejected(collectible.letter, slot));
+                    }
+                    CanCollect::No => {}
+                }
+            }
+        }
+
+        if !colliding {
+            self.rejected = None;
+        }
+
+        if self.puzzle.is_solved() && terrain.open_gate() {
+            events.push(Event::GateOpened);
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use collectible::Status;
+    use constant::WALKING_DURATION;
+
+    const PATH: u32 = 197;
+
+    fn terrain() -> Terrain {
+        Terrain::new(&[vec![vec![PATH; 6]]], Position::new(5, 0))
+    }
+
+    fn simulation(letters: &[(char, u32)]) -> Simulation {
+        let collectibles = letters
+            .iter()
+            .map(|(letter, x)| Collectible::new(*letter, Position::new(*x, 0)))
+            .collect();
+        Simulation::new("AB", collectibles, Position::new(0, 0))
+    }
+
+    fn walk_right(simulation: &mut Simulation, terrain: &mut Terrain, cells: u32) -> Vec<Event> {
+        let input = Input {
+            right: true,
+            ..Input::default()
+        };
+        let mut events = Vec::new();
+        for _ in 0..cells * 3 + 1 {
+            events.extend(simulation.step(&input, WALKING_DURATION / 2., terrain));
+        }
+        events
+            .into_iter()
+            .filter(|event| *event != Event::Footstep)
+            .collect()
+    }
+
+    #[test]
+    fn collecting_the_word_in_order_opens_the_gate() {
+        let mut terrain = terrain();
+        let mut simulation = simulation(&[('A', 1), ('B', 2)]);
+        let events = walk_right(&mut simulation, &mut terrain, 2);
+        assert_eq!(
+            events,
+            vec![
+                Event::Collected('A', 0),
+                Event::Collected('B', 1),
+                Event::GateOpened,
+            ]
+        );
+        assert_eq!(simulation.score(), 2);
+        assert!(simulation.puzzle.is_solved());
+        assert!(!terrain.gate().is_closed());
+    }
+
+    #[test]
+    fn closed_gate_blocks_the_player() {
+        let mut terrain = terrain();
+        let mut simulation = simulation(&[('B', 1)]);
+        let events = walk_right(&mut simulation, &mut terrain, 6);
+        assert_eq!(simulation.movement.position, Position::new(4, 0));
+        assert!(events.contains(&Event::Bump));
+        assert!(!simulation.is_at_gate(&terrain));
+    }
+
+    #[test]
+    fn wrong_letter_is_rejected_once_per_visit() {
+        let mut terrain = terrain();
+        let mut simulation = simulation(&[('B', 1), ('A', 2)]);
+        let events = walk_right(&mut simulation, &mut terrain, 2);
+        assert_eq!(
+            events,
+            vec![Event::Rejected('B', 0), Event::Collected('A', 0)]
+        );
+        assert_eq!(simulation.score(), 1);
+        assert!(!simulation.puzzle.is_solved());
+    }
+
+    #[test]
+    fn solved_level_lets_the_player_reach_the_gate() {
+        let mut terrain = terrain();
+        let mut simulation = simulation(&[('A', 1), ('B', 2)]);
+        walk_right(&mut simulation, &mut terrain, 5);
+        assert!(simulation.is_at_gate(&terrain));
+    }
+
+    #[test]
+    fn restart_resets_progress_and_closes_the_gate() {
+        let mut terrain = terrain();
+        let mut simulation = simulation(&[('A', 1), ('B', 2)]);
+        simulation.movement.set_walking_speed(2.);
+        walk_right(&mut simulation, &mut terrain, 3);
+        simulation.restart(&mut terrain);
+
+        assert_eq!(simulation.movement.position, Position::new(0, 0));
+        assert_eq!(simulation.movement.walking_speed(), 2.);
+        assert_eq!(simulation.score(), 0);
+        assert!(!simulation.puzzle.is_solved());
+        assert!(terrain.gate().is_closed());
+        assert!(simulation
+            .collectibles
+            .iter()
+            .all(|collectible| collectible.status == Status::NotCollected));
+    }
+}
@@ -0,0 +1,88 @@
+use assets::{Assets, Handle, Resource};
+use audio::{Audio, Channel};
+use clip::Clip;
+use quicksilver::Result;
+use std::collections::HashMap;
+
+const EFFECTS: [SoundEffect; 4] = [
+    SoundEffect::Footstep,
+    SoundEffect::Pickup,
+    SoundEffect::GateOpen,
+    SoundEffect::Bump,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    Footstep,
+    Pickup,
+    GateOpen,
+    Bump,
+}
+
+impl SoundEffect {
+    fn name(&self) -> &'static str {
+        match self {
+            SoundEffect::Footstep => "footstep",
+            SoundEffect::Pickup => "pickup",
+            SoundEffect::GateOpen => "gate",
+            SoundEffect::Bump => "bump",
+        }
+    }
+
+    fn cooldown(&self) -> f64 {
+        match self {
+            SoundEffect::Footstep => 250.,
+            SoundEffect::Pickup => 0.,
+            SoundEffect::GateOpen => 0.,
+            SoundEffect::Bump => 600.,
+        }
+    }
+
+    fn path(&self, theme: &str) -> String {
+        format!("resources/sfx/{}/{}", theme, self.name())
+    }
+}
+
+pub struct SoundEffects {
+    sounds: HashMap<SoundEffect, Handle<Clip>>,
+    cooldowns: HashMap<SoundEffect, f64>,
+}
+
+impl SoundEffects {
+    pub fn new(theme: &str, assets: &Assets) -> Self {
+        SoundEffects {
+            sounds: EFFECTS
+                .iter()
+                .map(|effect| (*effect, assets.sound(&effect.path(theme))))
+                .collect(),
+            cooldowns: HashMap::new(),
+        }
+    }
+
+    pub fn resources(theme: &str) -> Vec<Resource> {
+        EFFECTS
+            .iter()
+            .map(|effect| Resource::Sound(effect.path(theme)))
+            .collect()
+    }
+
+    pub fn update(&mut self, elapsed: f64) {
+        for cooldown in self.cooldowns.values_mut() {
+            *cooldown -= elapsed;
+        }
+        self.cooldowns.retain(|_, cooldown| *cooldown > 0.);
+    }
+
+    pub fn play(&mut self, effect: SoundEffect, audio: &mut Audio) -> Result<()> {
+        if self.cooldowns.contains_key(&effect) {
+            return Ok(());
+        }
+
+        if let Some(sound) = self.sounds.get(&effect) {
+            audio.play(sound, Channel::Sfx)?;
+            self.cooldowns.insert(effect, effect.cooldown());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use std::cmp::Reverse;
+use std::path::Path;
+#[cfg(target_arch = "wasm32")]
+use stdweb::unstable::TryInto;
+
+#[derive(Clone, Copy)]
+enum SoundFormat {
+    Ogg,
+    Mp3,
+    #[cfg(target_arch = "wasm32")]
+    M4a,
+    Wav,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+const FORMATS: [SoundFormat; 3] = [SoundFormat::Ogg, SoundFormat::Mp3, SoundFormat::Wav];
+
+#[cfg(target_arch = "wasm32")]
+const FORMATS: [SoundFormat; 4] = [
+    SoundFormat::Ogg,
+    SoundFormat::Mp3,
+    SoundFormat::M4a,
+    SoundFormat::Wav,
+];
+
+impl SoundFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            SoundFormat::Ogg => "ogg",
+            SoundFormat::Mp3 => "mp3",
+            #[cfg(target_arch = "wasm32")]
+            SoundFormat::M4a => "m4a",
+            SoundFormat::Wav => "wav",
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn rank(&self) -> u32 {
+        match self {
+            SoundFormat::Ogg => 3,
+            SoundFormat::Mp3 => 2,
+            SoundFormat::Wav => 1,
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn rank(&self) -> u32 {
+        let mime_type = match self {
+            SoundFormat::Ogg => "audio/ogg; codecs=\"vorbis\"",
+            SoundFormat::Mp3 => "audio/mpeg",
+            SoundFormat::M4a => "audio/mp4; codecs=\"mp4a.40.2\"",
+            SoundFormat::Wav => "audio/wav",
+        };
+        let support: String = js! { return new Audio().canPlayType(@{mime_type}); }
+            .try_into()
+            .unwrap_or_default();
+        match support.as_str() {
+            "probably" => 2,
+            "maybe" => 1,
+            _ => 0,
+        }
+    }
+}
+
+pub fn candidates(name: &str) -> Vec<String> {
+    if Path::new(name).extension().is_some() {
+        return vec![name.to_string()];
+    }
+
+    let mut formats: Vec<(u32, SoundFormat)> = FORMATS
+        .iter()
+        .map(|format| (format.rank(), *format))
+        .filter(|(rank, _)| *rank > 0)
+        .collect();
+    formats.sort_by_key(|format| Reverse(format.0));
+    formats
+        .iter()
+        .map(|(_, format)| format!("{}.{}", name, format.extension()))
+        .collect()
+}
+
+#[cfg(test)]
+pub fn is_on_disk(name: &str) -> bool {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("static");
+    candidates(name).iter().any(|path| root.join(path).exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn candidates_are_listed_best_first() {
+        assert_eq!(
+            candidates("resources/music/default"),
+            vec![
+                "resources/music/default.ogg",
+                "resources/music/default.mp3",
+                "resources/music/default.wav",
+            ]
+        );
+        assert_eq!(candidates("pickup.wav"), vec!["pickup.wav"]);
+    }
+}
@@ -1,13 +1,26 @@
+use assets::Handle;
+use constant::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use locale::Message;
+use profile_picker::ProfilePicker;
 use quicksilver::{
-    graphics::{Background::Img, Image},
+    geom::{Shape, Transform},
+    graphics::{Background::Img, Color, Image},
     input::{ButtonState, Key, MouseButton},
-    lifecycle::{Asset, Window},
+    lifecycle::Window,
     Result,
 };
+use scene::{Context, Scene, SceneChange};
+use settings::Settings;
+use text::TextStyle;
+use transition::{Effect, Transition};
+
+lazy_static! {
+    static ref HINT: TextStyle = TextStyle::new(24.0, Color::WHITE);
+}
 
 pub struct Splash {
     state: State,
-    image: Asset<Image>,
+    image: Handle<Image>,
 }
 
 enum State {
@@ -16,41 +29,57 @@ enum State {
 }
 
 impl Splash {
-    pub fn new() -> Self {
-        let image = Asset::new(Image::load("resources/images/splash.png"));
+    pub fn new(context: &Context) -> Self {
+        let image = context.assets.image("resources/images/splash.png");
         let state = State::Waiting;
 
         Self { state, image }
     }
+}
 
-    pub fn is_clicked(&self) -> bool {
-        match self.state {
-            State::Waiting => false,
-            State::Clicked => true,
-        }
-    }
-
-    pub fn update(&mut self, window: &mut Window) -> Result<()> {
+impl Scene for Splash {
+    fn update(&mut self, window: &mut Window, context: &mut Context) -> Result<SceneChange> {
         if let State::Clicked = self.state {
-            return Ok(());
+            return Ok(SceneChange::Stay);
         }
 
-        if window.mouse()[MouseButton::Left] == ButtonState::Released {
-            self.state = State::Clicked;
+        if window.keyboard()[Key::S] == ButtonState::Pressed {
+            return Ok(SceneChange::Push(Box::new(Settings::new())));
         }
 
-        if window.keyboard()[Key::Return].is_down() {
+        if window.mouse()[MouseButton::Left] == ButtonState::Released
+            || window.keyboard()[Key::Return] == ButtonState::Pressed
+        {
             self.state = State::Clicked;
+            context.music.start();
+            let profile_picker = ProfilePicker::new(context);
+            return Ok(SceneChange::Transition(
+                Transition::new(Effect::Fade),
+                Box::new(SceneChange::Replace(Box::new(profile_picker))),
+            ));
         }
 
-        Ok(())
+        Ok(SceneChange::Stay)
     }
 
-    pub fn draw(&mut self, window: &mut Window) -> Result<()> {
+    fn draw(&mut self, window: &mut Window, context: &mut Context) -> Result<()> {
         self.image.execute(|image| {
             window.draw(&image.area(), Img(&image));
             Ok(())
         })?;
+
+        let hint = context.config.language.text(Message::PressSForSettings);
+        if let Some(hint_text) = context.text.render(hint, &HINT)? {
+            window.draw_ex(
+                &hint_text
+                    .area()
+                    .with_center((WINDOW_WIDTH / 2, WINDOW_HEIGHT - 24)),
+                Img(&hint_text),
+                Transform::IDENTITY,
+                1,
+            );
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,232 @@
+use gate::Gate;
+use primitive::Position;
+use std::cmp::max;
+use std::collections::HashSet;
+use std::fmt;
+use tiled;
+
+lazy_static! {
+    static ref PATH_SET: HashSet<u32> = {
+        let mut m = HashSet::new();
+        m.insert(197);
+        m.insert(196);
+        m.insert(221);
+        m.insert(271);
+        m.insert(246);
+        m.insert(248);
+        m.insert(269);
+        m.insert(268);
+        m.insert(270);
+        m.insert(277);
+        m.insert(278);
+        m.insert(279);
+        m.insert(280);
+        m.insert(256);
+        m.insert(228);
+        m.insert(229);
+        m.insert(230);
+        m.insert(231);
+        m.insert(232);
+        m.insert(233);
+        m.insert(234);
+        m.insert(154);
+        m.insert(155);
+        m.insert(160);
+        m.insert(161);
+        m
+    };
+}
+
+#[derive(Clone, Copy)]
+pub enum Cell {
+    Path,
+    NonPath,
+    Empty,
+}
+
+impl fmt::Debug for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cell::Empty => write!(f, " "),
+            Cell::NonPath => write!(f, "#"),
+            Cell::Path => write!(f, "="),
+        }
+    }
+}
+
+pub type GridMap = Vec<Vec<Cell>>;
+
+pub struct Terrain {
+    grid: GridMap,
+    gate: Gate,
+}
+
+impl Terrain {
+    pub fn new(layers: &[Vec<Vec<u32>>], gate_position: Position) -> Self {
+        Terrain {
+            grid: Self::to_grid(layers),
+            gate: Gate::new(gate_position),
+        }
+    }
+
+    pub fn from_map(map: &tiled::Map, gate_position: Position) -> Self {
+        let layers: Vec<Vec<Vec<u32>>> =
+            map.layers.iter().map(|layer| layer.tiles.clone()).collect();
+        Self::new(&layers, gate_position)
+    }
+
+    pub fn grid(&self) -> &GridMap {
+        &self.grid
+    }
+
+    pub fn gate(&self) -> &Gate {
+        &self.gate
+    }
+
+    pub fn gate_position(&self) -> &Position {
+        &self.gate.position
+    }
+
+    pub fn columns(&self) -> usize {
+        self.grid.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.grid.len()
+    }
+
+    pub fn open_gate(&mut self) -> bool {
+        if !self.gate.is_closed() {
+            return false;
+        }
+
+        self.gate.open();
+        true
+    }
+
+    pub fn close_gate(&mut self) {
+        self.gate.close();
+    }
+
+    pub fn can_walk_to(&self, position: &Position) -> bool {
+        let x = position.x as usize;
+        let y = position.y as usize;
+        if self.grid.len() <= y || self.grid[y].len() <= x {
+            return false;
+        }
+
+        match self.grid[y][x] {
+            Cell::Path => !self.gate.is_gate(x as u32, y as u32),
+            Cell::NonPath => false,
+            Cell::Empty => false,
+        }
+    }
+
+    fn to_grid(layers: &[Vec<Vec<u32>>]) -> GridMap {
+        layers
+            .iter()
+            .map(|tiles| {
+                tiles
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|tile| {
+                                if tile == &0 {
+                                    return Cell::Empty;
+                                }
+                                if PATH_SET.contains(tile) {
+                                    return Cell::Path;
+                                }
+                                Cell::NonPath
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .fold(None, |a, b| match a {
+                None => Some(b),
+                Some(a) => Some(Self::join(a, b)),
+            })
+            .unwrap_or(Vec::new())
+    }
+
+    fn join(a: GridMap, b: GridMap) -> GridMap {
+        let max_y = max(a.len(), b.len());
+        let new_grid: GridMap = (0..max_y)
+            .map(|y| {
+                let row_a = a.get(y);
+                let row_b = b.get(y);
+                let max_x = max(
+                    row_a.map(|v| v.len()).unwrap_or(0),
+                    row_b.map(|v| v.len()).unwrap_or(0),
+                );
+                (0..max_x)
+                    .map(|x| {
+                        let grid_a = row_a.and_then(|v| v.get(x)).unwrap_or(&Cell::Empty);
+                        let grid_b = row_b.and_then(|v| v.get(x)).unwrap_or(&Cell::Empty);
+                        match (grid_a, grid_b) {
+                            (Cell::Path, Cell::Empty) => Cell::Path,
+                            (Cell::Empty, Cell::Empty) => Cell::Empty,
+                            (Cell::Empty, Cell::Path) => Cell::Path,
+                            (Cell::Path, Cell::Path) => Cell::Path,
+                            (_, Cell::NonPath) => Cell::NonPath,
+                            (Cell::NonPath, _) => Cell::NonPath,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        new_grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: u32 = 197;
+    const WALL: u32 = 1;
+
+    #[test]
+    fn only_path_tiles_are_walkable() {
+        let terrain = Terrain::new(&[vec![vec![PATH, WALL, 0]]], Position::new(9, 9));
+        assert!(terrain.can_walk_to(&Position::new(0, 0)));
+        assert!(!terrain.can_walk_to(&Position::new(1, 0)));
+        assert!(!terrain.can_walk_to(&Position::new(2, 0)));
+    }
+
+    #[test]
+    fn outside_the_map_is_not_walkable() {
+        let terrain = Terrain::new(&[vec![vec![PATH, PATH], vec![PATH]]], Position::new(9, 9));
+        assert!(!terrain.can_walk_to(&Position::new(2, 0)));
+        assert!(!terrain.can_walk_to(&Position::new(1, 1)));
+        assert!(!terrain.can_walk_to(&Position::new(0, 2)));
+        assert_eq!(terrain.columns(), 2);
+        assert_eq!(terrain.rows(), 2);
+    }
+
+    #[test]
+    fn layers_are_joined_with_walls_on_top() {
+        let ground = vec![vec![PATH, PATH, 0, 0]];
+        let decoration = vec![vec![0, WALL, PATH, 0, PATH]];
+        let terrain = Terrain::new(&[ground, decoration], Position::new(9, 9));
+        assert!(terrain.can_walk_to(&Position::new(0, 0)));
+        assert!(!terrain.can_walk_to(&Position::new(1, 0)));
+        assert!(terrain.can_walk_to(&Position::new(2, 0)));
+        assert!(!terrain.can_walk_to(&Position::new(3, 0)));
+        assert!(terrain.can_walk_to(&Position::new(4, 0)));
+    }
+
+    #[test]
+    fn closed_gate_blocks_the_path_until_opened() {
+        let mut terrain = Terrain::new(&[vec![vec![PATH; 3]; 3]], Position::new(1, 0));
+        assert!(!terrain.can_walk_to(&Position::new(1, 2)));
+        assert!(terrain.open_gate());
+        assert!(!terrain.open_gate());
+        assert!(terrain.can_walk_to(&Position::new(1, 2)));
+
+        terrain.close_gate();
+        assert!(!terrain.can_walk_to(&Position::new(1, 2)));
+    }
+}
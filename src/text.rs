@@ -0,0 +1,142 @@
+use accessibility::{Accessibility, FONT_FAMILIES};
+use assets::{Assets, Handle};
+use quicksilver::{
+    geom::{Rectangle, Transform, Vector},
+    graphics::{Background::Col, Color, Font, FontStyle, Image},
+    lifecycle::Window,
+    Result,
+};
+use std::collections::HashMap;
+
+pub const FONT_PATH: &str = "resources/fonts/slkscr.ttf";
+pub const READABLE_FONT_PATH: &str = "resources/fonts/DejaVuSans.ttf";
+const BACKDROP_PADDING: f32 = 4.;
+const MAX_CACHED_TEXTS: usize = 256;
+
+lazy_static! {
+    static ref BACKDROP: Color = Color::BLACK.with_alpha(0.85);
+}
+
+#[derive(Clone, Copy)]
+pub struct TextStyle {
+    size: f32,
+    color: Color,
+}
+
+impl TextStyle {
+    pub fn new(size: f32, color: Color) -> Self {
+        TextStyle { size, color }
+    }
+
+    fn key(&self) -> [u32; 5] {
+        [
+            self.size.to_bits(),
+            self.color.r.to_bits(),
+            self.color.g.to_bits(),
+            self.color.b.to_bits(),
+            self.color.a.to_bits(),
+        ]
+    }
+
+    fn scaled(&self, scale: f32) -> Self {
+        TextStyle::new(self.size * scale, self.color)
+    }
+
+    fn font_style(&self) -> FontStyle {
+        FontStyle::new(self.size, self.color)
+    }
+}
+
+pub struct Text {
+    assets: Assets,
+    font: Handle<Font>,
+    accessibility: Accessibility,
+    cache: HashMap<[u32; 5], HashMap<String, Image>>,
+    cached: usize,
+}
+
+impl Text {
+    pub fn new(assets: &Assets) -> Self {
+        for family in FONT_FAMILIES.iter() {
+            assets.font(family.path());
+        }
+
+        let accessibility = Accessibility::new();
+        Text {
+            assets: assets.clone(),
+            font: assets.font(accessibility.font.path()),
+            accessibility,
+            cache: HashMap::new(),
+            cached: 0,
+        }
+    }
+
+    pub fn set_accessibility(&mut self, accessibility: Accessibility) {
+        if self.accessibility.font != accessibility.font {
+            self.font = self.assets.font(accessibility.font.path());
+            self.clear_cache();
+        }
+
+        self.accessibility = accessibility;
+    }
+
+    pub fn backdrop(&self, window: &mut Window, area: Rectangle, z: u32) {
+        if !self.accessibility.high_contrast {
+            return;
+        }
+
+        let padding = Vector::new(BACKDROP_PADDING, BACKDROP_PADDING);
+        window.draw_ex(
+            &Rectangle::new(area.pos - padding, area.size + padding * 2),
+            Col(*BACKDROP),
+            Transform::IDENTITY,
+            z,
+        );
+    }
+
+    pub fn render(&mut self, text: &str, style: &TextStyle) -> Result<Option<Image>> {
+        let style = &style.scaled(self.accessibility.text_scale);
+        if let Some(image) = self
+            .cache
+            .get(&style.key())
+            .and_then(|rendered| rendered.get(text))
+        {
+            return Ok(Some(image.clone()));
+        }
+
+        let image = Self::rasterize(&self.font, text, style)?;
+        if let Some(ref image) = image {
+            if self.cached >= MAX_CACHED_TEXTS {
+                self.clear_cache();
+            }
+
+            self.cache
+                .entry(style.key())
+                .or_default()
+                .insert(text.to_string(), image.clone());
+            self.cached += 1;
+        }
+
+        Ok(image)
+    }
+
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+        self.cached = 0;
+    }
+
+    pub fn render_uncached(&self, text: &str, style: &TextStyle) -> Result<Option<Image>> {
+        let style = &style.scaled(self.accessibility.text_scale);
+        Self::rasterize(&self.font, text, style)
+    }
+
+    fn rasterize(font: &Handle<Font>, text: &str, style: &TextStyle) -> Result<Option<Image>> {
+        let mut image = None;
+        font.execute(|font| {
+            image = Some(font.render(text, &style.font_style())?);
+            Ok(())
+        })?;
+
+        Ok(image)
+    }
+}
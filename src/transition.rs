@@ -0,0 +1,159 @@
+use constant::{TRANSITION_Z, WINDOW_HEIGHT, WINDOW_WIDTH};
+use quicksilver::{
+    geom::{Rectangle, Shape, Transform, Triangle, Vector},
+    graphics::{Background::Col, Background::Img, Color},
+    lifecycle::Window,
+    Result,
+};
+use text::{Text, TextStyle};
+
+const DURATION: f64 = 500.;
+const TITLE_DURATION: f64 = 1500.;
+const IRIS_SEGMENTS: u32 = 48;
+
+lazy_static! {
+    static ref TITLE: TextStyle = TextStyle::new(42.0, Color::WHITE);
+}
+
+#[derive(Clone, Copy)]
+pub enum Effect {
+    Fade,
+    Iris,
+}
+
+#[derive(PartialEq)]
+enum Phase {
+    Out,
+    Hold,
+    In,
+    Done,
+}
+
+pub struct Transition {
+    effect: Effect,
+    phase: Phase,
+    timer: f64,
+    title: Option<String>,
+}
+
+impl Transition {
+    pub fn new(effect: Effect) -> Self {
+        Transition {
+            effect,
+            phase: Phase::Out,
+            timer: DURATION,
+            title: None,
+        }
+    }
+
+    pub fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn with_reduced_motion(mut self) -> Self {
+        self.effect = Effect::Fade;
+        self
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.phase == Phase::Done
+    }
+
+    pub fn update(&mut self, elapsed: f64) -> bool {
+        self.timer -= elapsed;
+        if self.timer > 0. {
+            return false;
+        }
+
+        match self.phase {
+            Phase::Out => {
+                self.phase = Phase::Hold;
+                self.timer = match self.title {
+                    Some(_) => TITLE_DURATION,
+                    None => 0.,
+                };
+                true
+            }
+            Phase::Hold => {
+                self.phase = Phase::In;
+                self.timer = DURATION;
+                false
+            }
+            Phase::In | Phase::Done => {
+                self.phase = Phase::Done;
+                false
+            }
+        }
+    }
+
+    pub fn draw(&mut self, window: &mut Window, text: &mut Text) -> Result<()> {
+        let coverage = match self.phase {
+            Phase::Out => 1. - (self.timer / DURATION).max(0.),
+            Phase::Hold => 1.,
+            Phase::In => (self.timer / DURATION).max(0.),
+            Phase::Done => 0.,
+        } as f32;
+
+        match self.effect {
+            Effect::Fade => Self::draw_fade(window, coverage),
+            Effect::Iris => Self::draw_iris(window, coverage),
+        }
+
+        if let (Phase::Hold, Some(title)) = (&self.phase, &self.title) {
+            if let Some(title_text) = text.render(title, &TITLE)? {
+                window.draw_ex(
+                    &title_text
+                        .area()
+                        .with_center((WINDOW_WIDTH / 2, WINDOW_HEIGHT / 2)),
+                    Img(&title_text),
+                    Transform::IDENTITY,
+                    TRANSITION_Z + 1,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw_fade(window: &mut Window, coverage: f32) {
+        window.draw_ex(
+            &Rectangle::new_sized((WINDOW_WIDTH, WINDOW_HEIGHT)),
+            Col(Color::BLACK.with_alpha(coverage)),
+            Transform::IDENTITY,
+            TRANSITION_Z,
+        );
+    }
+
+    fn draw_iris(window: &mut Window, coverage: f32) {
+        let center = Vector::new(WINDOW_WIDTH / 2, WINDOW_HEIGHT / 2);
+        let outer = center.len();
+        let inner = outer * (1. - coverage);
+        let step = 360. / IRIS_SEGMENTS as f32;
+        for segment in 0..IRIS_SEGMENTS {
+            let from = Vector::from_angle(segment as f32 * step);
+            let to = Vector::from_angle((segment + 1) as f32 * step);
+            let background = Col(Color::BLACK);
+            window.draw_ex(
+                &Triangle::new(
+                    center + from * inner,
+                    center + from * outer,
+                    center + to * outer,
+                ),
+                background,
+                Transform::IDENTITY,
+                TRANSITION_Z,
+            );
+            window.draw_ex(
+                &Triangle::new(
+                    center + from * inner,
+                    center + to * outer,
+                    center + to * inner,
+                ),
+                background,
+                Transform::IDENTITY,
+                TRANSITION_Z,
+            );
+        }
+    }
+}
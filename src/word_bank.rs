@@ -0,0 +1,69 @@
+pub struct Word {
+    pub text: &'static str,
+    pub unlocked_at: u32,
+}
+
+pub const WORDS: [Word; 2] = [
+    Word {
+        text: "APPLE",
+        unlocked_at: 0,
+    },
+    Word {
+        text: "JONATHAN",
+        unlocked_at: 1,
+    },
+];
+
+pub fn unlocked(level: u32) -> Vec<&'static str> {
+    WORDS
+        .iter()
+        .filter(|word| word.unlocked_at <= level)
+        .map(|word| word.text)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use instructions::Instructions;
+    use level_spec::LevelSpec;
+    use locale::LANGUAGES;
+    use sound_format;
+
+    #[test]
+    fn every_word_is_unlocked_by_a_level_it_fits() {
+        for word in WORDS.iter() {
+            let spec = LevelSpec::get(word.unlocked_at).unwrap();
+            assert!(spec.with_word(word.text).is_some(), "{}", word.text);
+        }
+    }
+
+    #[test]
+    fn words_are_listed_once() {
+        for (index, word) in WORDS.iter().enumerate() {
+            assert!(
+                WORDS[index + 1..]
+                    .iter()
+                    .all(|other| other.text != word.text),
+                "{}",
+                word.text
+            );
+        }
+    }
+
+    #[test]
+    fn every_voice_line_has_a_recording() {
+        for language in LANGUAGES.iter() {
+            let instructed = Instructions::instruction_sound(*language)
+                .iter()
+                .any(|sound| sound_format::is_on_disk(sound));
+            assert!(instructed, "{:?}", language);
+            for word in WORDS.iter() {
+                let recorded = Instructions::word_sound(word.text, *language)
+                    .iter()
+                    .any(|sound| sound_format::is_on_disk(sound));
+                assert!(recorded, "{:?} {}", language, word.text);
+            }
+        }
+    }
+}